@@ -0,0 +1,149 @@
+use crate::utils::grid::DenseGrid;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The answer to a puzzle part.
+///
+/// Integers that don't fit in an `i64` are kept as [Answer::BigInteger], which is serialized as a
+/// string since neither TOML nor JSON can represent them losslessly as numbers.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(#[serde(with = "big_integer")] i128),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::BigInteger(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Grid(rows) => write!(f, "{}", rows.iter().join("\n")),
+        }
+    }
+}
+
+impl Answer {
+    /// The value of an integer answer, whatever its size.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(n) => Some(*n as i128),
+            Answer::BigInteger(n) => Some(*n),
+            Answer::Text(_) | Answer::Grid(_) => None,
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_big_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                i64::try_from(value).map_or_else(
+                    |_| Answer::BigInteger(value as i128),
+                    Answer::Integer,
+                )
+            }
+        })*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, u8, u16, u32);
+impl_from_big_integer!(u64, usize, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl<T: Display + Clone> From<&DenseGrid<T>> for Answer {
+    fn from(grid: &DenseGrid<T>) -> Self {
+        Answer::Grid(grid.rows().map(|row| row.iter().join("")).collect())
+    }
+}
+
+mod big_integer {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &i128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+    struct Stored {
+        answer: Answer,
+    }
+
+    fn all_kinds() -> Vec<Answer> {
+        vec![
+            Answer::from(-42),
+            Answer::from(u64::MAX),
+            Answer::from("4,6,3,5,6,3,5,2,1,0"),
+            Answer::Grid(vec!["#..".to_owned(), ".#.".to_owned()]),
+        ]
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        assert_eq!(Answer::from(12usize), Answer::Integer(12));
+        assert_eq!(Answer::from(12i32), Answer::from(12u64));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(
+            Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]).to_string(),
+            "#.\n.#"
+        );
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        for answer in all_kinds() {
+            let stored = Stored { answer };
+            let serialized = toml::to_string(&stored).unwrap();
+            assert_eq!(stored, toml::from_str(&serialized).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        for answer in all_kinds() {
+            let serialized = serde_json::to_string(&answer).unwrap();
+            assert_eq!(answer, serde_json::from_str(&serialized).unwrap());
+        }
+    }
+}
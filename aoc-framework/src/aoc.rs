@@ -0,0 +1,1007 @@
+use crate::answer::Answer;
+use crate::bench::format_duration;
+pub use crate::context::{SolverContext, Verbosity};
+use crate::external::ExternalSolver;
+use crate::parsing::{find_ints, split_sections};
+pub use crate::parsing::{Captured, FromCaptures, Normalization, Section, TrailingNewline, Trim};
+use crate::stress::GenerateFn;
+pub use crate::stress::Rng;
+use crate::utils::grid::{DenseGrid, XY};
+pub use aoc_macros::aoc;
+use itertools::Itertools;
+use regex::Regex;
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub type Year = u32;
+pub type Day = usize;
+pub type Part = usize;
+pub type SolverMap = HashMap<(Year, Day), DaySolver>;
+
+/// Output of a day's parse stage, shared by both parts.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+type ParseFn = Box<dyn Fn(&PuzzleInput) -> Result<Parsed, SolveError> + Send + Sync>;
+type PartFn = Box<dyn Fn(&PuzzleInput, &Parsed) -> Result<Answer, SolveError> + Send + Sync>;
+type Variants = Vec<(&'static str, PartFn)>;
+
+#[allow(clippy::test_attr_in_doctest)]
+/// Declare a test to run a part
+///
+/// Panics with a different message depending on whether the part is not implemented,
+/// failed to parse the input or failed while solving.
+///
+/// Parameters can be appended after the input, for puzzles whose examples use different
+/// dimensions than the real input (see [Lines::param]).
+///
+/// Usage:
+/// ```rust,ignore
+/// #[aoc(year = 2024, day = 24, part = 1)]
+/// fn part_1(input: impl Lines) -> u64 {
+///     // implementation
+///     3159281
+/// }
+///
+/// #[test]
+/// fn test_part_1() {
+///     aoc_test!(2024, 24, 1, 3159281, "8172638174891\n19294378171");
+///     aoc_test!(2024, 24, 1, 3159281, "8172638174891\n19294378171", width = 7);
+/// }
+/// ```
+#[macro_export]
+macro_rules! aoc_test {
+    ($year:expr, $day:expr, $part:literal, $expected:expr, $content:expr $(, $param:ident = $value:expr)* $(,)?) => {
+        let solver = $crate::aoc::get_solvers()
+            .remove(&($year, $day))
+            .unwrap_or_else(|| panic!("{} day {} is not solved", $year, $day));
+        let input = solver.prepare(PuzzleInput::from($content)$(.with_param(stringify!($param), $value))*);
+        let result = match solver.solve(&input, $part) {
+            Ok(answer) => answer,
+            Err($crate::aoc::SolveError::NotImplemented) => {
+                panic!("part {} is not implemented", $part)
+            }
+            Err($crate::aoc::SolveError::Parse(e)) => panic!("failed to parse input: {e}"),
+            Err(e) => panic!("solver failed: {e}"),
+        };
+        assert_eq!(result, $crate::answer::Answer::from($expected));
+    };
+}
+
+/// The text of an input, with how the solvers should read it.
+///
+/// Cloning it doesn't copy the text, so that each run can get its own [SolverContext].
+#[derive(Clone)]
+pub struct PuzzleInput {
+    input: Arc<str>,
+    /// The text before normalization, kept only when it differs from `input`.
+    original: Option<Arc<str>>,
+    normalization: Normalization,
+    params: Params,
+    context: SolverContext,
+}
+
+impl PuzzleInput {
+    /// Reads `input` with the default [Normalization].
+    pub fn new(input: String) -> Self {
+        PuzzleInput {
+            input: Arc::from(input),
+            original: None,
+            normalization: Normalization::RAW,
+            params: Params::default(),
+            context: SolverContext::silent(),
+        }
+        .with_normalization(Normalization::DEFAULT)
+    }
+
+    /// Normalizes the input again from its original text.
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        let original = self.original.take().unwrap_or(self.input);
+        (self.input, self.original) = match normalization.apply(&original) {
+            Cow::Borrowed(_) => (original, None),
+            Cow::Owned(normalized) => (Arc::from(normalized), Some(original)),
+        };
+        self.normalization = normalization;
+        self
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.set(name, value);
+        self
+    }
+
+    pub fn with_params(mut self, params: Params) -> Self {
+        self.params.extend(params);
+        self
+    }
+
+    /// The parameters set on this input, e.g. to pass them on to an external solver.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Sets where the solvers report progress and debug output, silent by default.
+    pub fn with_context(mut self, context: SolverContext) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Named values that change how an input is solved, like the size of the grid the puzzle
+/// happens in, which is often smaller in the examples than in the real input.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn set(&mut self, name: &str, value: impl ToString) {
+        self.values.insert(name.to_owned(), value.to_string());
+    }
+
+    pub fn extend(&mut self, other: Params) {
+        self.values.extend(other.values);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Parses the value of parameter `name`, or returns `default` if it isn't set.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T, SolveError>
+    where
+        T::Err: Display,
+    {
+        self.values
+            .get(name)
+            .map_or(Ok(default), |value| value.parse::<T>())
+            .map_err(|e| SolveError::InvalidParam {
+                name: name.to_owned(),
+                message: e.to_string(),
+            })
+    }
+}
+
+/// Formats the parameters the way [Params::from_str] parses them, sorted by name.
+impl Display for Params {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .iter()
+            .sorted()
+            .map(|(name, value)| format!("{name}={value}"))
+            .join(",");
+        f.write_str(&params)
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parses a comma separated list of parameters like `width=7,height=7`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for param in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (name, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("expected a parameter like name=value, got {param:?}"))?;
+            params.set(name.trim(), value.trim());
+        }
+        Ok(params)
+    }
+}
+
+pub trait Lines {
+    fn get_raw(&self) -> &str;
+    fn get_params(&self) -> &Params;
+    fn get_context(&self) -> &SolverContext;
+    /// The lines of the input, trimmed as set by its [Normalization].
+    fn get_lines(&self) -> impl Iterator<Item = &str> {
+        let normalization = self.get_normalization();
+        self.get_raw()
+            .lines()
+            .map(move |line| normalization.trim_line(line))
+    }
+
+    fn get_normalization(&self) -> Normalization {
+        Normalization::DEFAULT
+    }
+
+    /// The input as bytes, for parsers that only deal with ASCII, without copying it.
+    fn get_bytes(&self) -> &[u8] {
+        self.get_raw().as_bytes()
+    }
+
+    /// Same as [Lines::get_lines], as bytes.
+    fn byte_lines(&self) -> impl Iterator<Item = &[u8]> {
+        let normalization = self.get_normalization();
+        let bytes = self.get_bytes();
+        let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+        bytes
+            .split(|&b| b == b'\n')
+            .filter(move |_| !bytes.is_empty())
+            .map(move |line| normalization.trim_bytes(line))
+    }
+
+    /// The whole input, which [Lines::get_raw] is only a part of for a [Section].
+    fn get_source(&self) -> &str {
+        self.get_raw()
+    }
+
+    /// Builds a [ParseError] pointing at `token`, which must be a slice of this input
+    /// (e.g. a line from [Lines::get_lines] or a word split from it).
+    fn parse_error(&self, token: &str, message: impl Display) -> ParseError {
+        ParseError::at(self.get_source(), token, message)
+    }
+
+    /// Same as [Lines::parse_error], for a slice of [Lines::get_bytes].
+    fn byte_error(&self, token: &[u8], message: impl Display) -> ParseError {
+        let source = self.get_source();
+        let mut offset = (token.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|&offset| offset <= source.len())
+            .unwrap_or(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        ParseError::at(source, &source[offset..offset], message)
+    }
+
+    /// Splits the input into the blocks separated by blank lines.
+    fn sections(&self) -> impl Iterator<Item = Section<'_>> {
+        split_sections(self.get_raw())
+            .into_iter()
+            .map(|text| Section {
+                source: self.get_source(),
+                text,
+                params: self.get_params(),
+                context: self.get_context(),
+                normalization: self.get_normalization(),
+            })
+    }
+
+    /// Parses the integers in `text`, which must be a slice of this input.
+    fn ints_in<T: FromStr>(&self, text: &str) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        find_ints(text)
+            .into_iter()
+            .map(|n| n.parse().map_err(|e| self.parse_error(n, e)))
+            .collect()
+    }
+
+    /// Parses the integers of every non-empty line, e.g. `[190, 10, 19]` for `190: 10 19`.
+    fn ints<T: FromStr>(&self) -> Result<Vec<Vec<T>>, ParseError>
+    where
+        T::Err: Display,
+    {
+        self.get_lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| self.ints_in(line))
+            .collect()
+    }
+
+    /// Reads the non-empty lines as a grid, with one cell per character.
+    fn grid<T: TryFrom<char> + Clone>(&self) -> Result<DenseGrid<T>, ParseError> {
+        let mut rows = self.get_lines().filter(|line| !line.is_empty()).peekable();
+        let width = rows
+            .peek()
+            .ok_or_else(|| self.parse_error(self.get_raw(), "the grid is empty"))?
+            .chars()
+            .count();
+        let mut cells = vec![];
+        for row in rows {
+            if row.chars().count() != width {
+                return Err(self.parse_error(row, format!("expected {width} cells in every row")));
+            }
+            for (i, c) in row.char_indices() {
+                let cell = T::try_from(c)
+                    .map_err(|_| self.parse_error(&row[i..], format!("invalid cell {c:?}")))?;
+                cells.push(cell);
+            }
+        }
+        Ok(DenseGrid::from_iter(width, cells.into_iter()))
+    }
+
+    /// The position of the only `symbol` in the grid read by [Lines::grid], e.g. where a path
+    /// starts.
+    fn grid_position(&self, symbol: char) -> Result<XY, ParseError> {
+        let mut positions = self
+            .get_lines()
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.char_indices()
+                    .enumerate()
+                    .filter(|&(_, (_, c))| c == symbol)
+                    .map(move |(x, (i, _))| (XY::from((x as i64, y as i64)), &row[i..]))
+            });
+        let raw = self.get_raw();
+        let (position, _) = positions.next().ok_or_else(|| {
+            self.parse_error(&raw[raw.len()..], format!("no {symbol:?} in the grid"))
+        })?;
+        if let Some((_, token)) = positions.next() {
+            return Err(self.parse_error(token, format!("more than one {symbol:?} in the grid")));
+        }
+        Ok(position)
+    }
+
+    /// Builds a value from every match of `regex` in the input.
+    fn captures_into<T: FromCaptures>(&self, regex: &Regex) -> Result<Vec<T>, ParseError> {
+        regex
+            .captures_iter(self.get_raw())
+            .map(|captures| {
+                T::from_captures(&Captured {
+                    source: self.get_source(),
+                    captures,
+                })
+            })
+            .collect()
+    }
+
+    /// Reads a parameter set by a test or on the command line, e.g. `--param width=7`.
+    fn param<T: FromStr>(&self, name: &str, default: T) -> Result<T, SolveError>
+    where
+        T::Err: Display,
+    {
+        self.get_params().get(name, default)
+    }
+}
+
+impl Lines for PuzzleInput {
+    fn get_raw(&self) -> &str {
+        &self.input
+    }
+
+    fn get_params(&self) -> &Params {
+        &self.params
+    }
+
+    fn get_context(&self) -> &SolverContext {
+        &self.context
+    }
+
+    fn get_normalization(&self) -> Normalization {
+        self.normalization
+    }
+}
+
+impl Lines for &PuzzleInput {
+    fn get_raw(&self) -> &str {
+        &self.input
+    }
+
+    fn get_params(&self) -> &Params {
+        &self.params
+    }
+
+    fn get_context(&self) -> &SolverContext {
+        &self.context
+    }
+
+    fn get_normalization(&self) -> Normalization {
+        self.normalization
+    }
+}
+
+impl<'a> From<&'a str> for PuzzleInput {
+    fn from(value: &'a str) -> Self {
+        Self::new(value.to_owned())
+    }
+}
+
+impl From<String> for PuzzleInput {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&Vec<String>> for PuzzleInput {
+    fn from(value: &Vec<String>) -> Self {
+        Self::new(value.join("\n"))
+    }
+}
+
+/// Every parser and part registered with [aoc], collected when linking.
+#[linkme::distributed_slice]
+pub static SOLVERS: [Registration];
+
+/// A function registered with [aoc].
+pub struct Registration {
+    pub year: Year,
+    pub day: Day,
+    /// Where the function is declared, the variants of a part are ordered by it
+    pub line: u32,
+    /// Set with `normalize = ...`, for the whole day
+    pub normalization: Option<Normalization>,
+    pub kind: RegistrationKind,
+}
+
+pub enum RegistrationKind {
+    Parser(fn(&PuzzleInput) -> Result<Parsed, SolveError>),
+    Part {
+        part: Part,
+        name: &'static str,
+        solver: fn(&PuzzleInput, &Parsed) -> Result<Answer, SolveError>,
+    },
+    Metadata(&'static Metadata),
+    Generator(GenerateFn),
+}
+
+/// What a day's puzzle is about and how it's solved, registered with [aoc] on a constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    pub title: &'static str,
+    /// Techniques used by the solutions, like `grid`, `bfs` or `dp`
+    pub tags: &'static [&'static str],
+    /// Time or space complexity of the solutions, in a few words
+    pub complexity: Option<&'static str>,
+    pub uses_rayon: bool,
+}
+
+impl Metadata {
+    pub const EMPTY: Metadata = Metadata {
+        title: "",
+        tags: &[],
+        complexity: None,
+        uses_rayon: false,
+    };
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// Builds the solvers of every day from the registered functions.
+///
+/// Panics if a day has more than one parser, metadata or generator, or registrations with
+/// different normalizations.
+pub fn get_solvers() -> SolverMap {
+    let mut solvers = SolverMap::new();
+    for registration in SOLVERS.iter().sorted_by_key(|r| (r.year, r.day, r.line)) {
+        let (year, day) = (registration.year, registration.day);
+        let solver = solvers.entry((year, day)).or_insert_with(DaySolver::empty);
+        if let Some(normalization) = registration.normalization {
+            if solver.normalization.is_some_and(|n| n != normalization) {
+                panic!("{year} day {day} has several normalizations");
+            }
+            solver.normalization = Some(normalization);
+        }
+        match registration.kind {
+            RegistrationKind::Parser(parser) => {
+                if solver.parser.is_some() {
+                    panic!("{year} day {day} has several parsers");
+                }
+                solver.parser = Some(Box::new(parser));
+            }
+            RegistrationKind::Part {
+                part,
+                name,
+                solver: part_solver,
+            } => {
+                solver
+                    .parts
+                    .entry(part)
+                    .or_default()
+                    .push((name, Box::new(part_solver)));
+            }
+            RegistrationKind::Metadata(metadata) => {
+                if solver.metadata.is_some() {
+                    panic!("{year} day {day} has several metadata");
+                }
+                solver.metadata = Some(metadata);
+            }
+            RegistrationKind::Generator(generator) => {
+                if solver.generator.is_some() {
+                    panic!("{year} day {day} has several generators");
+                }
+                solver.generator = Some(generator);
+            }
+        }
+    }
+    solvers
+}
+
+/// Gets the output of the parser, for the parts registered with [aoc] that take it.
+#[doc(hidden)]
+pub fn get_parsed<T: 'static>(parsed: &Parsed) -> Result<&T, SolveError> {
+    parsed.downcast_ref::<T>().ok_or_else(|| {
+        SolveError::Runtime(format!(
+            "the parsed input isn't a {}, is the parser registered?",
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
+/// The solvers of a day, with an optional parse stage whose output is shared by both parts.
+///
+/// Each part can have several named variants, the first one being the default.
+pub struct DaySolver {
+    parser: Option<ParseFn>,
+    parts: HashMap<Part, Variants>,
+    normalization: Option<Normalization>,
+    metadata: Option<&'static Metadata>,
+    generator: Option<GenerateFn>,
+    externals: Vec<Arc<ExternalSolver>>,
+}
+
+impl DaySolver {
+    /// A day without any part, to add the solvers to.
+    pub(crate) fn empty() -> Self {
+        DaySolver {
+            parser: None,
+            parts: HashMap::new(),
+            normalization: None,
+            metadata: None,
+            generator: None,
+            externals: vec![],
+        }
+    }
+
+    /// Starts a day whose parts read the input directly, to build one without [aoc] in tests.
+    #[cfg(test)]
+    pub(crate) fn builder() -> DaySolverBuilder {
+        DaySolverBuilder {
+            solver: DaySolver::empty(),
+        }
+    }
+
+    pub fn has_parser(&self) -> bool {
+        self.parser.is_some()
+    }
+
+    /// Normalizes the input the way the day reads it, to do before parsing and solving it.
+    pub fn prepare(&self, input: PuzzleInput) -> PuzzleInput {
+        match self.normalization {
+            Some(normalization) => input.with_normalization(normalization),
+            None => input,
+        }
+    }
+
+    /// Runs the parse stage, or does nothing if the parts read the input directly.
+    pub fn parse(&self, input: &PuzzleInput) -> Result<Parsed, SolveError> {
+        match &self.parser {
+            Some(parser) => parser(input),
+            None => Ok(Box::new(())),
+        }
+    }
+
+    pub fn metadata(&self) -> Option<&'static Metadata> {
+        self.metadata
+    }
+
+    pub fn has_generator(&self) -> bool {
+        self.generator.is_some()
+    }
+
+    /// Builds a random input of about `size` elements, the same one for the same `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> Option<PuzzleInput> {
+        let generator = self.generator?;
+        Some(self.prepare(generator(&mut Rng::with_seed(seed), size)))
+    }
+
+    /// Adds a solver running in another process as a variant of its parts, which is the default
+    /// one of the parts that aren't solved natively.
+    ///
+    /// Its name is leaked, to be a `&'static str` like the names of the native variants that
+    /// [DaySolver::variants] gives out. The external solvers are only added once at startup,
+    /// from the entries of the external solvers file, so this leaks one name per entry rather
+    /// than growing during the run.
+    pub fn add_external(&mut self, external: ExternalSolver) {
+        let external = Arc::new(external);
+        let name: &'static str = Box::leak(external.name.clone().into_boxed_str());
+        for &part in &external.parts {
+            let run = external.clone();
+            let solver: PartFn =
+                Box::new(move |input, _| run.run(input, part).map(|run| run.answer));
+            self.parts.entry(part).or_default().push((name, solver));
+        }
+        self.externals.push(external);
+    }
+
+    /// The external solver behind a variant, to measure its process instead of the harness.
+    pub fn external(&self, variant: &str) -> Option<&ExternalSolver> {
+        self.externals
+            .iter()
+            .map(Arc::as_ref)
+            .find(|external| external.name == variant)
+    }
+
+    /// Names of the implementations of a part, starting with the default one.
+    pub fn variants(&self, part: Part) -> Vec<&'static str> {
+        self.parts
+            .get(&part)
+            .map(|variants| variants.iter().map(|&(name, _)| name).collect())
+            .unwrap_or_default()
+    }
+
+    /// Solves a part from the output of [DaySolver::parse] on the same input.
+    pub fn solve_parsed(
+        &self,
+        input: &PuzzleInput,
+        parsed: &Parsed,
+        part: Part,
+    ) -> Result<Answer, SolveError> {
+        let (_, solver) = self
+            .parts
+            .get(&part)
+            .and_then(|variants| variants.first())
+            .ok_or(SolveError::NotImplemented)?;
+        solver(input, parsed)
+    }
+
+    /// Same as [DaySolver::solve_parsed], with the implementation named `variant`.
+    pub fn solve_variant(
+        &self,
+        input: &PuzzleInput,
+        parsed: &Parsed,
+        part: Part,
+        variant: &str,
+    ) -> Result<Answer, SolveError> {
+        let (_, solver) = self
+            .parts
+            .get(&part)
+            .and_then(|variants| variants.iter().find(|&&(name, _)| name == variant))
+            .ok_or(SolveError::NotImplemented)?;
+        solver(input, parsed)
+    }
+
+    /// Parses the input and solves a part.
+    pub fn solve(&self, input: &PuzzleInput, part: Part) -> Result<Answer, SolveError> {
+        if !self.parts.contains_key(&part) {
+            return Err(SolveError::NotImplemented);
+        }
+        self.solve_parsed(input, &self.parse(input)?, part)
+    }
+}
+
+#[cfg(test)]
+pub(crate) struct DaySolverBuilder {
+    solver: DaySolver,
+}
+
+#[cfg(test)]
+impl DaySolverBuilder {
+    /// Adds an implementation of a part, the first one added being the default.
+    pub(crate) fn part<R: IntoAnswer>(
+        mut self,
+        part: Part,
+        name: &'static str,
+        solver: impl Fn(&PuzzleInput) -> R + Send + Sync + 'static,
+    ) -> Self {
+        let solver: PartFn = Box::new(move |input, _| solver(input).into_answer());
+        self.solver
+            .parts
+            .entry(part)
+            .or_default()
+            .push((name, solver));
+        self
+    }
+
+    pub(crate) fn generator(mut self, generator: GenerateFn) -> Self {
+        self.solver.generator = Some(generator);
+        self
+    }
+
+    pub(crate) fn build(self) -> DaySolver {
+        self.solver
+    }
+}
+
+/// Converts the return value of a solver function into an [Answer].
+///
+/// Solvers can return a plain value if they can't fail, or a `Result` otherwise.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, SolveError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, SolveError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: Into<SolveError>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, SolveError> {
+        self.map(Into::into).map_err(Into::into)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+pub enum SolveError {
+    #[error("not implemented")]
+    NotImplemented,
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    #[error("invalid parameter {name}: {message}")]
+    InvalidParam { name: String, message: String },
+    #[error("{0}")]
+    Runtime(String),
+    #[error("cancelled")]
+    Cancelled,
+    #[error("timed out after {}", format_duration(*.0))]
+    TimedOut(Duration),
+}
+
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+#[error("line {line}, column {column}: {message}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Finds where `token` starts inside `source` and reports it as a 1-based line and column.
+    ///
+    /// If `token` isn't a slice of `source`, the error points at the end of the input.
+    pub fn at(source: &str, token: &str, message: impl Display) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|&offset| offset <= source.len())
+            .unwrap_or(source.len());
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.to_string(),
+        }
+    }
+}
+
+pub trait PuzzleSource {
+    fn get_input(&self, year: Year, day: Day) -> Result<PuzzleInput, Box<dyn std::error::Error>>;
+}
+
+pub struct FixedDataSource {
+    pub lines: Vec<String>,
+}
+
+impl PuzzleSource for FixedDataSource {
+    fn get_input(&self, _year: Year, _day: Day) -> Result<PuzzleInput, Box<dyn std::error::Error>> {
+        Ok(PuzzleInput::from(&self.lines))
+    }
+}
+
+pub fn get_days_iter() -> impl Iterator<Item = Day> {
+    1..=25
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn get_solver(year: Year, day: Day) -> DaySolver {
+        get_solvers().remove(&(year, day)).unwrap()
+    }
+
+    #[aoc(year = 2000, day = 100, part = 1)]
+    fn sum_lines(input: impl Lines) -> Result<u64, ParseError> {
+        input
+            .get_lines()
+            .map(|l| l.parse::<u64>().map_err(|e| input.parse_error(l, e)))
+            .sum()
+    }
+
+    #[aoc(year = 2000, day = 101, parser)]
+    #[aoc(year = 2000, day = 103, parser)]
+    fn parse_numbers(input: &PuzzleInput) -> Result<Vec<u64>, ParseError> {
+        input
+            .get_lines()
+            .map(|l| l.parse::<u64>().map_err(|e| input.parse_error(l, e)))
+            .collect()
+    }
+
+    #[aoc(year = 2000, day = 101, part = 1)]
+    #[aoc(year = 2000, day = 103, part = 1)]
+    fn sum(numbers: &[u64]) -> u64 {
+        numbers.iter().sum()
+    }
+
+    #[aoc(year = 2000, day = 101, part = 2)]
+    #[aoc(year = 2000, day = 103, part = 2)]
+    fn max(numbers: &[u64]) -> u64 {
+        numbers.iter().copied().max().unwrap_or_default()
+    }
+
+    #[aoc(year = 2000, day = 103, part = 1)]
+    fn sum_loop(numbers: &[u64]) -> u64 {
+        let mut total = 0;
+        for n in numbers {
+            total += n;
+        }
+        total
+    }
+
+    #[aoc(year = 2000, day = 102, part = 1)]
+    fn sum_scaled(input: impl Lines) -> Result<u64, SolveError> {
+        let factor = input.param("factor", 1)?;
+        Ok(sum_lines(input)? * factor)
+    }
+
+    #[aoc(year = 2000, day = 104, part = 1)]
+    fn count_bytes(input: &[u8]) -> usize {
+        input.iter().filter(|b| b.is_ascii_digit()).count()
+    }
+
+    #[aoc(year = 2000, day = 104, part = 2)]
+    fn count_lines(input: &str) -> usize {
+        input.lines().count()
+    }
+
+    #[aoc(year = 2000, day = 105, parser)]
+    fn parse_words(input: &str) -> Vec<String> {
+        input.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[aoc(year = 2000, day = 105, part = 1)]
+    fn longest_word(words: &[String]) -> String {
+        words
+            .iter()
+            .max_by_key(|w| w.len())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[aoc(year = 2000, day = 106, part = 1, normalize = Normalization::RAW)]
+    fn indentation(input: impl Lines) -> usize {
+        input
+            .get_lines()
+            .map(|line| line.len() - line.trim_start().len())
+            .sum()
+    }
+
+    #[aoc(year = 2000, day = 107, part = 1)]
+    fn count_reported(input: &str, context: &SolverContext) -> usize {
+        input.lines().inspect(|line| context.progress(line)).count()
+    }
+
+    #[test]
+    fn test_implemented_solver() {
+        aoc_test!(2000, 100, 1, 6, "1\n2\n3");
+    }
+
+    #[test]
+    fn test_not_implemented_solver() {
+        let input: PuzzleInput = "1".into();
+        let result = get_solver(2000, 100).solve(&input, 2);
+        assert_eq!(result, Err(SolveError::NotImplemented));
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input: PuzzleInput = "1\n2\n  x3".into();
+        let result = get_solver(2000, 100).solve(&input, 1);
+        let Err(SolveError::Parse(error)) = result else {
+            panic!("expected a parse error, got {result:?}");
+        };
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+
+    #[test]
+    fn test_grid_position() {
+        let input: PuzzleInput = "..#\n.S.\n".into();
+        assert_eq!(input.grid_position('S').unwrap(), XY::from((1, 1)));
+        let missing = input.grid_position('E').unwrap_err();
+        assert_eq!((missing.line, missing.column), (3, 1));
+        let input: PuzzleInput = ".S\nS.".into();
+        let twice = input.grid_position('S').unwrap_err();
+        assert_eq!((twice.line, twice.column), (2, 1));
+    }
+
+    #[test]
+    fn test_params() {
+        aoc_test!(2000, 102, 1, 6, "1\n2\n3");
+        aoc_test!(2000, 102, 1, 60, "1\n2\n3", factor = 10);
+    }
+
+    #[test]
+    fn test_invalid_param() {
+        let input = PuzzleInput::from("1").with_param("factor", "ten");
+        let result = get_solver(2000, 102).solve(&input, 1);
+        assert!(matches!(result, Err(SolveError::InvalidParam { name, .. }) if name == "factor"));
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let params = "width=7, height = 11".parse::<Params>().unwrap();
+        assert_eq!(params.get("width", 0), Ok(7));
+        assert_eq!(params.get("height", 0), Ok(11));
+        assert_eq!(params.get("bytes", 1024), Ok(1024));
+        assert!("width".parse::<Params>().is_err());
+    }
+
+    #[test]
+    fn test_shared_parser() {
+        aoc_test!(2000, 101, 1, 6, "1\n2\n3");
+        aoc_test!(2000, 101, 2, 3, "1\n2\n3");
+    }
+
+    #[test]
+    fn test_variants() {
+        let input: PuzzleInput = "4\n5".into();
+        let solver = get_solver(2000, 103);
+        assert_eq!(solver.variants(1), vec!["sum", "sum_loop"]);
+        assert_eq!(solver.variants(2), vec!["max"]);
+
+        let parsed = solver.parse(&input).unwrap();
+        assert_eq!(
+            solver.solve_variant(&input, &parsed, 1, "sum_loop"),
+            Ok(Answer::from(9))
+        );
+        assert_eq!(
+            solver.solve_variant(&input, &parsed, 2, "sum_loop"),
+            Err(SolveError::NotImplemented)
+        );
+    }
+
+    #[test]
+    fn test_parse_once() {
+        let input: PuzzleInput = "4\n5".into();
+        let solver = get_solver(2000, 101);
+        assert!(solver.has_parser());
+        let parsed = solver.parse(&input).unwrap();
+        assert_eq!(solver.solve_parsed(&input, &parsed, 1), Ok(Answer::from(9)));
+        assert_eq!(solver.solve_parsed(&input, &parsed, 2), Ok(Answer::from(5)));
+    }
+
+    #[test]
+    fn test_input_types() {
+        aoc_test!(2000, 104, 1, 4, "12\n34");
+        aoc_test!(2000, 104, 2, 2, "12\n34");
+        aoc_test!(2000, 105, 1, "parsed", "a parsed input");
+    }
+
+    #[test]
+    fn test_day_normalization() {
+        aoc_test!(2000, 106, 1, 3, "a\n  b\r\n c");
+        let input = get_solver(2000, 106).prepare(PuzzleInput::from("a\r\n"));
+        assert_eq!(input.get_raw(), "a\r\n");
+    }
+
+    #[test]
+    fn test_context() {
+        let reported = Arc::new(Mutex::new(vec![]));
+        let sink = reported.clone();
+        let context = SolverContext::with_sink(Verbosity::Progress, move |_, message| {
+            sink.lock().unwrap().push(message.to_string())
+        });
+        let input = PuzzleInput::from("a\nb").with_context(context);
+        assert_eq!(get_solver(2000, 107).solve(&input, 1), Ok(Answer::from(2)));
+        assert_eq!(*reported.lock().unwrap(), vec!["a", "b"]);
+    }
+
+    #[aoc(year = 2000, day = 107)]
+    const METADATA: Metadata = Metadata {
+        title: "Progress Report",
+        tags: &["lines"],
+        ..Metadata::EMPTY
+    };
+
+    #[test]
+    fn test_metadata() {
+        let solvers = get_solvers();
+        let metadata = solvers[&(2000, 107)].metadata().unwrap();
+        assert_eq!(metadata.title, "Progress Report");
+        assert!(metadata.has_tag("Lines"));
+        assert!(!metadata.has_tag("grid"));
+        assert!(solvers[&(2000, 106)].metadata().is_none());
+    }
+
+    #[test]
+    fn test_registered_days() {
+        let solvers = get_solvers();
+        assert!(solvers[&(2000, 101)].has_parser());
+        assert!(!solvers[&(2000, 104)].has_parser());
+        assert_eq!(solvers[&(2000, 105)].variants(1), vec!["longest_word"]);
+    }
+}
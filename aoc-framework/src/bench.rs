@@ -0,0 +1,311 @@
+use crate::aoc::SolveError;
+use crate::external::ProcessRun;
+pub use peak_alloc::PeakAlloc;
+use serde::{Deserialize, Serialize};
+use std::cmp::min;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Reads the memory usage, which is only measured when the binary registers [PeakAlloc] as its
+/// global allocator.
+static PEAK_ALLOC: PeakAlloc = PeakAlloc;
+
+const TARGET_DURATION_PER_PART: Duration = Duration::from_secs(5);
+const MAX_RUNS: usize = 1_000_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkResults {
+    pub iterations: usize,
+    pub average_duration: Duration,
+    pub peak_memory: usize,
+    /// Average duration of the day's parse stage, which isn't included in `average_duration`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_duration: Option<Duration>,
+    /// Whether the part was stopped after `average_duration` instead of being measured
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+}
+
+impl BenchmarkResults {
+    /// Records a part that didn't finish within `timeout`.
+    pub fn after_timeout(timeout: Duration) -> Self {
+        BenchmarkResults {
+            iterations: 0,
+            average_duration: timeout,
+            peak_memory: 0,
+            parse_duration: None,
+            timed_out: true,
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_micros(1) {
+        format!("{}ns", duration.subsec_nanos())
+    } else if duration < Duration::from_millis(1) {
+        format!("{:.1}µs", duration.as_secs_f64() * 1_000_000.0)
+    } else if duration < Duration::from_secs(1) {
+        format!("{:.1}ms", duration.as_secs_f64() * 1_000.0)
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+const ONE_KB: usize = 1024;
+const ONE_MB: usize = ONE_KB * 1024;
+
+pub fn format_memory(bytes: usize) -> String {
+    if bytes < ONE_KB {
+        format!("{bytes} bytes")
+    } else if bytes < ONE_MB {
+        format!("{} KiB", bytes / ONE_KB)
+    } else {
+        format!("{} MiB", bytes / ONE_MB)
+    }
+}
+
+impl Display for BenchmarkResults {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.timed_out {
+            return write!(
+                f,
+                "timed out after {}",
+                format_duration(self.average_duration)
+            );
+        }
+        let iter = match self.iterations {
+            1 => "1 iteration".to_string(),
+            n => format!("{} iterations", n),
+        };
+
+        let parse = self
+            .parse_duration
+            .map(|d| format!(" (+{} parse)", format_duration(d)))
+            .unwrap_or_default();
+
+        write!(
+            f,
+            "{}{parse} / {} peak ({iter})",
+            format_duration(self.average_duration),
+            format_memory(self.peak_memory),
+        )
+    }
+}
+
+pub fn benchmark<T, F: Fn() -> Result<T, SolveError>>(
+    bench_fn: F,
+) -> Result<BenchmarkResults, BenchmarkError> {
+    // run the function to get an idea of how long it takes
+    let start = Instant::now();
+    let _ = bench_fn()?;
+    benchmark_after_first_run(start.elapsed(), bench_fn)
+}
+
+/// Same as [benchmark], for a function that already ran once in `first_run_duration`, e.g. to
+/// check that it finishes within a timeout.
+pub fn benchmark_after_first_run<T, F: Fn() -> Result<T, SolveError>>(
+    first_run_duration: Duration,
+    bench_fn: F,
+) -> Result<BenchmarkResults, BenchmarkError> {
+    // measure the memory usage
+    // it's important that this is done in a second run because the stdlib might allocate
+    // things when first called, which would mess up the memory usage for part 1
+    PEAK_ALLOC.reset_peak_usage();
+    let initial_mem = PEAK_ALLOC.current_usage();
+
+    bench_fn()?;
+
+    let peak_mem = PEAK_ALLOC.peak_usage();
+    let used_mem = peak_mem - initial_mem;
+
+    if first_run_duration > TARGET_DURATION_PER_PART {
+        Ok(BenchmarkResults {
+            iterations: 1,
+            average_duration: first_run_duration,
+            peak_memory: used_mem,
+            parse_duration: None,
+            timed_out: false,
+        })
+    } else {
+        let project_runs = (TARGET_DURATION_PER_PART.as_secs_f64()
+            / first_run_duration.as_secs_f64())
+        .ceil() as usize;
+        let iterations = min(MAX_RUNS, project_runs);
+
+        let start = Instant::now();
+        for _ in 0..iterations {
+            bench_fn()?;
+        }
+        let duration = start.elapsed();
+
+        Ok(BenchmarkResults {
+            iterations,
+            average_duration: duration / (iterations as u32),
+            peak_memory: used_mem,
+            parse_duration: None,
+            timed_out: false,
+        })
+    }
+}
+
+/// Benchmarks a solver running in another process, using the wall time and the peak resident
+/// set size of the process that it reports.
+pub fn benchmark_process<F: Fn() -> Result<ProcessRun, SolveError>>(
+    run_fn: F,
+) -> Result<BenchmarkResults, BenchmarkError> {
+    let first_run = run_fn()?;
+    benchmark_process_after_first_run(first_run, run_fn)
+}
+
+/// Same as [benchmark_process], for a process that already ran once as `first_run`.
+pub fn benchmark_process_after_first_run<F: Fn() -> Result<ProcessRun, SolveError>>(
+    first_run: ProcessRun,
+    run_fn: F,
+) -> Result<BenchmarkResults, BenchmarkError> {
+    let iterations = if first_run.wall_time > TARGET_DURATION_PER_PART {
+        1
+    } else {
+        let project_runs = (TARGET_DURATION_PER_PART.as_secs_f64()
+            / first_run.wall_time.as_secs_f64())
+        .ceil() as usize;
+        min(MAX_RUNS, project_runs)
+    };
+
+    let mut total_duration = first_run.wall_time;
+    let mut peak_memory = first_run.peak_memory;
+    for _ in 1..iterations {
+        let run = run_fn()?;
+        total_duration += run.wall_time;
+        peak_memory = peak_memory.max(run.peak_memory);
+    }
+
+    Ok(BenchmarkResults {
+        iterations,
+        average_duration: total_duration / (iterations as u32),
+        peak_memory,
+        parse_duration: None,
+        timed_out: false,
+    })
+}
+
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+pub enum BenchmarkError {
+    #[error("not implemented")]
+    NotImplemented,
+    #[error("solver failed: {0}")]
+    Failed(SolveError),
+}
+
+impl From<SolveError> for BenchmarkError {
+    fn from(error: SolveError) -> Self {
+        match error {
+            SolveError::NotImplemented => BenchmarkError::NotImplemented,
+            error => BenchmarkError::Failed(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    fn very_fast_solver() -> Result<(), SolveError> {
+        sleep(Duration::from_millis(10));
+        Ok(())
+    }
+
+    fn very_slow_solver() -> Result<(), SolveError> {
+        sleep(Duration::from_millis(2000));
+        Ok(())
+    }
+
+    fn not_implemented_solver() -> Result<(), SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+
+    fn failing_solver() -> Result<(), SolveError> {
+        Err(SolveError::Runtime("no path".to_owned()))
+    }
+
+    #[allow(clippy::useless_vec)]
+    fn alloc_vec_solver() -> Result<i64, SolveError> {
+        let vec = vec![1, 2, 3, 4];
+        Ok(vec.iter().sum())
+    }
+
+    fn factorial_stack(n: f64) -> f64 {
+        if n > 1.0 {
+            n * factorial_stack(n - 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    #[test]
+    fn test_benchmark_slow_solver() {
+        let bench = benchmark(very_slow_solver);
+        assert!(bench.is_ok());
+        assert_eq!(1, bench.unwrap().iterations);
+    }
+
+    #[test]
+    fn test_benchmark_fast_solver() {
+        let bench = benchmark(very_fast_solver);
+        assert!(bench.is_ok());
+        assert!(bench.unwrap().iterations > 10);
+    }
+
+    #[test]
+    fn test_benchmark_fails() {
+        let bench = benchmark(not_implemented_solver);
+        assert_eq!(Some(BenchmarkError::NotImplemented), bench.err());
+    }
+
+    #[test]
+    fn test_benchmark_solver_error() {
+        let bench = benchmark(failing_solver);
+        assert_eq!(
+            Some(BenchmarkError::Failed(SolveError::Runtime(
+                "no path".to_owned()
+            ))),
+            bench.err()
+        );
+    }
+
+    #[test]
+    fn test_benchmark_later_error() {
+        let runs = std::cell::Cell::new(0);
+        let bench = benchmark(|| {
+            runs.set(runs.get() + 1);
+            if runs.get() < 3 {
+                Ok(())
+            } else {
+                failing_solver()
+            }
+        });
+        assert!(matches!(bench, Err(BenchmarkError::Failed(_))));
+    }
+
+    #[test]
+    fn test_timed_out_results() {
+        let results = BenchmarkResults::after_timeout(Duration::from_secs(10));
+        assert_eq!(results.to_string(), "timed out after 10.0s");
+    }
+
+    #[ignore]
+    #[test]
+    fn test_benchmark_alloc_vec_solver() {
+        let bench = benchmark(alloc_vec_solver);
+        assert!(bench.is_ok());
+        assert!(bench.unwrap().peak_memory >= 4 * 4); // 4 * i64 numbers
+    }
+
+    #[ignore]
+    #[test]
+    fn test_benchmark_stack_memory_solver() {
+        let bench = benchmark(|| Ok(factorial_stack(1000.0)));
+        assert!(bench.is_ok());
+        assert_eq!(bench.unwrap().peak_memory, 0);
+    }
+}
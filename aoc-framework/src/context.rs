@@ -0,0 +1,337 @@
+use crate::aoc::SolveError;
+use crate::visualize::Frame;
+use std::fmt::{Arguments, Display};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Solvers run on their own thread when they have a timeout, with as much stack as the main one.
+const SOLVER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// How many solvers that timed out are still running in the background.
+static ABANDONED_SOLVERS: AtomicUsize = AtomicUsize::new(0);
+
+// states of a solver thread, to count it as abandoned exactly while it runs after its timeout
+const RUNNING: u8 = 0;
+const FINISHED: u8 = 1;
+const ABANDONED: u8 = 2;
+
+/// Marks the thread of a solver as finished when dropped, even when the solver panics.
+struct SolverThread {
+    state: Arc<AtomicU8>,
+}
+
+impl Drop for SolverThread {
+    fn drop(&mut self) {
+        if self.state.swap(FINISHED, Ordering::SeqCst) == ABANDONED {
+            ABANDONED_SOLVERS.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+/// How many solvers that timed out are still running in the background, taking CPU time and
+/// memory from everything that runs after them.
+pub fn abandoned_solvers() -> usize {
+    ABANDONED_SOLVERS.load(Ordering::SeqCst)
+}
+
+/// Waits up to `timeout` for the solvers that timed out to notice that they were cancelled,
+/// and returns how many are still running.
+pub fn wait_for_abandoned_solvers(timeout: Duration) -> usize {
+    let start = Instant::now();
+    loop {
+        let running = abandoned_solvers();
+        if running == 0 || start.elapsed() >= timeout {
+            return running;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// How much the solvers report while they run, from `-q` to `-v` on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    /// Progress of the solvers that take a while, the default.
+    Progress,
+    /// Everything the solvers print to debug themselves.
+    Debug,
+}
+
+type Sink = Arc<dyn Fn(Verbosity, Arguments) + Send + Sync>;
+type FrameSink = Arc<dyn Fn(Frame) + Send + Sync>;
+
+/// Where solvers report progress and debug output, instead of printing to stdout, and learn
+/// that they should give up.
+///
+/// The harness sets it on the input, and solvers get it from [Lines::get_context] or by taking
+/// a `&SolverContext` argument after the input. It is silent by default, like in tests and
+/// benchmarks.
+///
+/// [Lines::get_context]: crate::aoc::Lines::get_context
+#[derive(Clone)]
+pub struct SolverContext {
+    verbosity: Verbosity,
+    sink: Sink,
+    cancelled: Arc<AtomicBool>,
+    /// What the solver explained, only collected for `solve --explain`
+    explanation: Option<Arc<Mutex<Vec<String>>>>,
+    /// Where the frames go, only set for the `visualize` command
+    frames: Option<FrameSink>,
+}
+
+impl SolverContext {
+    /// Reports to stderr, so that the answers are the only thing on stdout.
+    pub fn new(verbosity: Verbosity) -> Self {
+        Self::with_sink(verbosity, |level, message| match level {
+            Verbosity::Debug => eprintln!("  {message}"),
+            _ => eprintln!("{message}"),
+        })
+    }
+
+    pub fn silent() -> Self {
+        Self::new(Verbosity::Quiet)
+    }
+
+    /// Sends the messages up to `verbosity` to `sink`.
+    pub fn with_sink(
+        verbosity: Verbosity,
+        sink: impl Fn(Verbosity, Arguments) + Send + Sync + 'static,
+    ) -> Self {
+        SolverContext {
+            verbosity,
+            sink: Arc::new(sink),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            explanation: None,
+            frames: None,
+        }
+    }
+
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// Whether messages of `level` are shown, to skip building expensive ones.
+    pub fn enabled(&self, level: Verbosity) -> bool {
+        level != Verbosity::Quiet && level <= self.verbosity
+    }
+
+    /// Reports how far a long search got, e.g. `context.progress(format_args!("a: {a}"))`.
+    ///
+    /// The message is only formatted if it is shown.
+    pub fn progress(&self, message: impl Display) {
+        self.report(Verbosity::Progress, message);
+    }
+
+    /// Reports intermediate values, only shown with `-v`.
+    pub fn debug(&self, message: impl Display) {
+        self.report(Verbosity::Debug, message);
+    }
+
+    /// Starts collecting the explanations of the solvers using this context, and its clones, in
+    /// a new buffer.
+    pub fn explaining(self) -> Self {
+        SolverContext {
+            explanation: Some(Arc::default()),
+            ..self
+        }
+    }
+
+    /// Whether the solver should explain its answer, to skip keeping what it needs otherwise.
+    pub fn is_explaining(&self) -> bool {
+        self.explanation.is_some()
+    }
+
+    /// Explains how the solver got its answer, like the path it found or a grid with it drawn
+    /// on, to debug wrong answers.
+    pub fn explain(&self, explanation: impl Display) {
+        if let Some(buffer) = &self.explanation {
+            buffer.lock().unwrap().push(explanation.to_string());
+        }
+    }
+
+    /// Takes what was explained so far.
+    pub fn take_explanation(&self) -> Vec<String> {
+        self.explanation
+            .as_ref()
+            .map(|buffer| std::mem::take(&mut *buffer.lock().unwrap()))
+            .unwrap_or_default()
+    }
+
+    /// Sends the frames recorded by the solvers using this context, and its clones, to `sink`.
+    pub fn recording(self, sink: impl Fn(Frame) + Send + Sync + 'static) -> Self {
+        SolverContext {
+            frames: Some(Arc::new(sink)),
+            ..self
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.frames.is_some()
+    }
+
+    /// Records a step of a simulation, only rendered when it's being visualized.
+    pub fn frame(&self, render: impl FnOnce() -> Frame) {
+        if let Some(sink) = &self.frames {
+            sink(render());
+        }
+    }
+
+    /// Asks the solvers using this context, and its clones, to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Fails with [SolveError::Cancelled] once the run is cancelled, to call regularly in loops
+    /// that can take a while.
+    pub fn check_cancelled(&self) -> Result<(), SolveError> {
+        if self.is_cancelled() {
+            Err(SolveError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Runs `run` with a copy of this context that gets cancelled after `timeout`, and gives up
+    /// on it with [SolveError::TimedOut] at that point.
+    ///
+    /// Solvers that never check for cancellation keep running in the background until the
+    /// program exits, but the harness moves on without them. [abandoned_solvers] counts them.
+    pub fn run_with_timeout<T: Send + 'static>(
+        &self,
+        timeout: Option<Duration>,
+        run: impl FnOnce(SolverContext) -> Result<T, SolveError> + Send + 'static,
+    ) -> Result<T, SolveError> {
+        let context = SolverContext {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            ..self.clone()
+        };
+        let Some(timeout) = timeout else {
+            return run(context);
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let run_context = context.clone();
+        let state = Arc::new(AtomicU8::new(RUNNING));
+        let solver_thread = SolverThread {
+            state: state.clone(),
+        };
+        thread::Builder::new()
+            .stack_size(SOLVER_STACK_SIZE)
+            .spawn(move || {
+                let _solver_thread = solver_thread;
+                sender.send(run(run_context))
+            })
+            .map_err(|e| SolveError::Runtime(format!("failed to start the solver: {e}")))?;
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                context.cancel();
+                // counted first, the thread can only uncount it once it sees it abandoned
+                ABANDONED_SOLVERS.fetch_add(1, Ordering::SeqCst);
+                if state.swap(ABANDONED, Ordering::SeqCst) == FINISHED {
+                    ABANDONED_SOLVERS.fetch_sub(1, Ordering::SeqCst);
+                }
+                Err(SolveError::TimedOut(timeout))
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(SolveError::Runtime("the solver panicked".to_owned()))
+            }
+        }
+    }
+
+    fn report(&self, level: Verbosity, message: impl Display) {
+        if self.enabled(level) {
+            (self.sink)(level, format_args!("{message}"));
+        }
+    }
+}
+
+impl Default for SolverContext {
+    fn default() -> Self {
+        Self::silent()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity() {
+        let messages = Arc::new(Mutex::new(vec![]));
+        let sink = messages.clone();
+        let context = SolverContext::with_sink(Verbosity::Progress, move |level, message| {
+            sink.lock().unwrap().push((level, message.to_string()))
+        });
+        context.progress(format_args!("a: {}", 1));
+        context.debug("hidden");
+        assert!(!context.enabled(Verbosity::Debug));
+        assert_eq!(
+            *messages.lock().unwrap(),
+            vec![(Verbosity::Progress, "a: 1".to_owned())]
+        );
+
+        assert!(!SolverContext::silent().enabled(Verbosity::Progress));
+    }
+
+    #[test]
+    fn test_explain() {
+        let context = SolverContext::silent();
+        context.explain("ignored");
+        assert!(!context.is_explaining());
+        assert!(context.take_explanation().is_empty());
+
+        let context = context.explaining();
+        context.clone().explain(format_args!("{} + {}", 1, 2));
+        assert_eq!(context.take_explanation(), vec!["1 + 2"]);
+        assert!(context.take_explanation().is_empty());
+    }
+
+    fn count_until_cancelled(context: SolverContext) -> Result<u64, SolveError> {
+        let mut count = 0u64;
+        loop {
+            context.check_cancelled()?;
+            count = count.wrapping_add(1);
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let context = SolverContext::silent();
+        let timeout = Some(Duration::from_millis(50));
+        assert_eq!(context.run_with_timeout(timeout, |_| Ok(1)), Ok(1));
+        assert_eq!(
+            context.run_with_timeout(timeout, count_until_cancelled),
+            Err(SolveError::TimedOut(Duration::from_millis(50)))
+        );
+        // the timeout only cancelled the copy given to the solver
+        assert!(!context.is_cancelled());
+        assert_eq!(
+            context.run_with_timeout(None, |context| {
+                context.cancel();
+                context.check_cancelled()
+            }),
+            Err(SolveError::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_abandoned_solvers() {
+        let timeout = Some(Duration::from_millis(20));
+        let result = SolverContext::silent().run_with_timeout(timeout, |_| {
+            // ignores the cancellation
+            thread::sleep(Duration::from_millis(200));
+            Ok(())
+        });
+        assert_eq!(result, Err(SolveError::TimedOut(Duration::from_millis(20))));
+        assert!(abandoned_solvers() >= 1);
+        assert_eq!(wait_for_abandoned_solvers(Duration::from_secs(5)), 0);
+    }
+}
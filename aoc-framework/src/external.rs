@@ -0,0 +1,341 @@
+//! Solvers written in other languages, run as separate processes.
+//!
+//! They are declared in a TOML file like:
+//! ```toml
+//! [[solver]]
+//! year = 2024
+//! day = 1
+//! name = "python"
+//! command = ["python3", "other/day01.py"]
+//! ```
+//!
+//! The process gets the input on stdin, the part in `AOC_PART` (along with `AOC_YEAR` and
+//! `AOC_DAY`) and each parameter in `AOC_PARAM_<NAME>`, and prints the answer on stdout. What it
+//! prints on stderr is shown as debug output.
+
+use crate::answer::Answer;
+use crate::aoc::{
+    Day, DaySolver, Lines, Part, PuzzleInput, SolveError, SolverContext, SolverMap, Year,
+};
+use serde::Deserialize;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long to wait between checks that the process exited, at most, which bounds how much the
+/// wall time is overestimated.
+const MAX_POLL_DELAY: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Deserialize)]
+struct ExternalSolversFile {
+    #[serde(default, rename = "solver")]
+    solvers: Vec<ExternalSolver>,
+}
+
+/// A solver running an executable, registered as a variant of the parts of its day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ExternalSolver {
+    pub year: Year,
+    pub day: Day,
+    /// The name of the variant, e.g. the language it's written in
+    pub name: String,
+    /// The executable and its arguments
+    pub command: Vec<String>,
+    #[serde(default = "both_parts")]
+    pub parts: Vec<Part>,
+}
+
+fn both_parts() -> Vec<Part> {
+    vec![1, 2]
+}
+
+/// The outcome of running an external solver once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessRun {
+    pub answer: Answer,
+    pub wall_time: Duration,
+    /// Peak resident set size of the process, in bytes
+    pub peak_memory: usize,
+}
+
+impl ExternalSolver {
+    /// Runs the process on `input`, killing it if the context of the input gets cancelled.
+    pub fn run(&self, input: &PuzzleInput, part: Part) -> Result<ProcessRun, SolveError> {
+        let (program, args) = self
+            .command
+            .split_first()
+            .ok_or_else(|| SolveError::Runtime(format!("{} has an empty command", self.name)))?;
+        let mut command = Command::new(program);
+        command
+            .args(args)
+            .env("AOC_YEAR", self.year.to_string())
+            .env("AOC_DAY", self.day.to_string())
+            .env("AOC_PART", part.to_string())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        for (name, value) in input.params().iter() {
+            command.env(format!("AOC_PARAM_{}", name.to_uppercase()), value);
+        }
+
+        let start = Instant::now();
+        let mut child = command
+            .spawn()
+            .map_err(|e| SolveError::Runtime(format!("failed to start {}: {e}", self.name)))?;
+
+        let mut stdin = child.stdin.take().unwrap();
+        let stdin_input = input.clone();
+        // a process that doesn't read all of its input closes the pipe early, which is fine
+        let writer = thread::spawn(move || {
+            let _ = stdin.write_all(stdin_input.get_raw().as_bytes());
+        });
+        let stdout = read_in_background(child.stdout.take().unwrap());
+        let stderr = read_in_background(child.stderr.take().unwrap());
+
+        let waited = wait(&mut child, input.get_context());
+        let wall_time = start.elapsed();
+        let _ = writer.join();
+        let (status, peak_memory) = waited?;
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+
+        let context = input.get_context();
+        for line in String::from_utf8_lossy(&stderr).lines() {
+            context.debug(format_args!("{}: {line}", self.name));
+        }
+        if !status.success() {
+            return Err(SolveError::Runtime(format!(
+                "{} failed with {status}",
+                self.name
+            )));
+        }
+        let answer = parse_answer(&String::from_utf8_lossy(&stdout))
+            .ok_or_else(|| SolveError::Runtime(format!("{} printed no answer", self.name)))?;
+
+        Ok(ProcessRun {
+            answer,
+            wall_time,
+            peak_memory,
+        })
+    }
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut output = vec![];
+        let _ = pipe.read_to_end(&mut output);
+        output
+    })
+}
+
+/// Reads an answer the same way as the native ones: numbers are integers, and several lines are
+/// a grid.
+fn parse_answer(output: &str) -> Option<Answer> {
+    let output = output.trim();
+    if output.is_empty() {
+        None
+    } else if output.contains('\n') {
+        Some(Answer::Grid(
+            output
+                .lines()
+                .map(|line| line.trim_end().to_owned())
+                .collect(),
+        ))
+    } else if let Ok(n) = output.parse::<i128>() {
+        Some(Answer::from(n))
+    } else {
+        Some(Answer::from(output))
+    }
+}
+
+/// Waits for the process to exit, and gets its peak memory usage.
+#[cfg(unix)]
+fn wait(child: &mut Child, context: &SolverContext) -> Result<(ExitStatus, usize), SolveError> {
+    use std::os::unix::process::ExitStatusExt;
+
+    // the unit of `ru_maxrss`
+    const RSS_UNIT: usize = if cfg!(target_os = "macos") { 1 } else { 1024 };
+
+    let pid = child.id() as libc::pid_t;
+    let mut delay = Duration::from_micros(10);
+    loop {
+        let mut status = 0;
+        // SAFETY: rusage is plain data, and the child is only reaped here, so the pid can't
+        // belong to another process yet
+        let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+        match unsafe { libc::wait4(pid, &mut status, libc::WNOHANG, &mut usage) } {
+            0 => {}
+            -1 => return Err(wait_error(io::Error::last_os_error())),
+            _ => {
+                let peak_memory = usage.ru_maxrss as usize * RSS_UNIT;
+                return Ok((ExitStatus::from_raw(status), peak_memory));
+            }
+        }
+        if context.is_cancelled() {
+            return Err(kill(child));
+        }
+        thread::sleep(delay);
+        delay = (delay * 2).min(MAX_POLL_DELAY);
+    }
+}
+
+/// Waits for the process to exit, without measuring its memory usage.
+#[cfg(not(unix))]
+fn wait(child: &mut Child, context: &SolverContext) -> Result<(ExitStatus, usize), SolveError> {
+    let mut delay = Duration::from_micros(10);
+    loop {
+        if let Some(status) = child.try_wait().map_err(wait_error)? {
+            return Ok((status, 0));
+        }
+        if context.is_cancelled() {
+            return Err(kill(child));
+        }
+        thread::sleep(delay);
+        delay = (delay * 2).min(MAX_POLL_DELAY);
+    }
+}
+
+fn kill(child: &mut Child) -> SolveError {
+    let _ = child.kill();
+    let _ = child.wait();
+    SolveError::Cancelled
+}
+
+fn wait_error(error: io::Error) -> SolveError {
+    SolveError::Runtime(format!("failed to wait for the solver: {error}"))
+}
+
+/// Reads the external solvers declared in `path`, if it exists.
+pub fn load_external_solvers(path: &Path) -> Result<Vec<ExternalSolver>, ExternalSolverError> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            log::debug!("no external solvers, {} doesn't exist", path.display());
+            return Ok(vec![]);
+        }
+        Err(e) => {
+            return Err(ExternalSolverError::Read {
+                path: path.to_owned(),
+                message: e.to_string(),
+            })
+        }
+    };
+    let file: ExternalSolversFile =
+        toml::from_str(&contents).map_err(|e| ExternalSolverError::Read {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })?;
+    Ok(file.solvers)
+}
+
+/// Adds the external solvers to the native ones, creating the days that only have external
+/// solvers.
+pub fn register_external_solvers(
+    solvers: &mut SolverMap,
+    external_solvers: Vec<ExternalSolver>,
+) -> Result<(), ExternalSolverError> {
+    for external in external_solvers {
+        let (year, day) = (external.year, external.day);
+        let solver = solvers.entry((year, day)).or_insert_with(DaySolver::empty);
+        if external
+            .parts
+            .iter()
+            .any(|&part| solver.variants(part).contains(&external.name.as_str()))
+        {
+            return Err(ExternalSolverError::DuplicateName {
+                year,
+                day,
+                name: external.name,
+            });
+        }
+        solver.add_external(external);
+    }
+    Ok(())
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+pub enum ExternalSolverError {
+    #[error("failed to read external solvers from {path}: {message}")]
+    Read { path: PathBuf, message: String },
+    #[error("{year} day {day} already has a solver named {name}")]
+    DuplicateName { year: Year, day: Day, name: String },
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn shell_solver(name: &str, script: &str) -> ExternalSolver {
+        ExternalSolver {
+            year: 2024,
+            day: 108,
+            name: name.to_owned(),
+            command: vec!["sh".to_owned(), "-c".to_owned(), script.to_owned()],
+            parts: both_parts(),
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let solver = shell_solver("shell", "wc -l; echo counted >&2");
+        let run = solver.run(&PuzzleInput::from("a\nb\nc\n"), 1).unwrap();
+        assert_eq!(run.answer, Answer::Integer(3));
+        assert!(run.peak_memory > 0);
+
+        let solver = shell_solver("shell", r#"echo "$AOC_PART-$AOC_PARAM_WIDTH""#);
+        let input = PuzzleInput::from("").with_param("width", 7);
+        assert_eq!(solver.run(&input, 2).unwrap().answer, Answer::from("2-7"));
+
+        let solver = shell_solver("shell", "exit 3");
+        assert!(matches!(
+            solver.run(&input, 1),
+            Err(SolveError::Runtime(message)) if message.contains("failed")
+        ));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let solver = shell_solver("shell", "sleep 10");
+        let context = SolverContext::silent();
+        let input = PuzzleInput::from("").with_context(context.clone());
+        context.cancel();
+        assert_eq!(solver.run(&input, 1), Err(SolveError::Cancelled));
+    }
+
+    #[test]
+    fn test_register() {
+        let file: ExternalSolversFile = toml::from_str(
+            r#"
+            [[solver]]
+            year = 2024
+            day = 108
+            name = "shell"
+            command = ["sh", "-c", "echo 42"]
+            parts = [2]
+            "#,
+        )
+        .unwrap();
+        let mut solvers = SolverMap::new();
+        register_external_solvers(&mut solvers, file.solvers.clone()).unwrap();
+
+        let solver = &solvers[&(2024, 108)];
+        assert_eq!(solver.variants(1), Vec::<&str>::new());
+        assert_eq!(solver.variants(2), vec!["shell"]);
+        assert_eq!(
+            solver.solve(&PuzzleInput::from(""), 2),
+            Ok(Answer::Integer(42))
+        );
+        assert!(solver.external("shell").is_some());
+
+        assert_eq!(
+            register_external_solvers(&mut solvers, file.solvers),
+            Err(ExternalSolverError::DuplicateName {
+                year: 2024,
+                day: 108,
+                name: "shell".to_owned()
+            })
+        );
+    }
+}
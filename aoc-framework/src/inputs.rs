@@ -0,0 +1,850 @@
+use crate::answer::Answer;
+use crate::aoc::{Day, Part, PuzzleInput, PuzzleSource, Year};
+use crate::known_answers::AnswerStore;
+use crate::submissions::SubmissionHistory;
+use crate::submit::{parse_response, SubmitOutcome};
+use directories::ProjectDirs;
+use reqwest::blocking::Client;
+use reqwest::header::COOKIE;
+use reqwest::Proxy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The environment variable holding the session token.
+pub const TOKEN_VARIABLE: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The website asks automated requests to say where they come from.
+pub const DEFAULT_USER_AGENT: &str = concat!("aoc-framework/", env!("CARGO_PKG_VERSION"));
+const TOKEN_FILE: &str = "token.txt";
+
+/// How to talk to the website, shared by all the requests of a [CachedOnlinePuzzleSource].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpOptions {
+    /// Where the website is, e.g. a local server in tests
+    pub base_url: String,
+    pub user_agent: String,
+    /// How long a whole request can take
+    pub timeout: Option<Duration>,
+    pub connect_timeout: Option<Duration>,
+    /// The proxy of all the requests, instead of the ones of the system
+    pub proxy: Option<String>,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        HttpOptions {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            timeout: Some(Duration::from_secs(30)),
+            connect_timeout: Some(Duration::from_secs(10)),
+            proxy: None,
+        }
+    }
+}
+
+impl HttpOptions {
+    /// The defaults, replaced by the `AOC_BASE_URL`, `AOC_USER_AGENT`, `AOC_TIMEOUT`,
+    /// `AOC_CONNECT_TIMEOUT` and `AOC_PROXY` environment variables that are set. Timeouts are in
+    /// seconds, and 0 disables them.
+    pub fn from_env() -> Result<Self, OnlinePuzzleSourceCreateError> {
+        Self::from_variables(|name| std::env::var(name).ok())
+    }
+
+    fn from_variables(
+        variable: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, OnlinePuzzleSourceCreateError> {
+        let timeout = |name| {
+            variable(name)
+                .map(|timeout| {
+                    timeout
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                        .map(|timeout| Some(timeout).filter(|timeout| !timeout.is_zero()))
+                        .ok_or_else(|| {
+                            OnlinePuzzleSourceCreateError::InvalidHttpOptions(format!(
+                                "{name} should be a number of seconds, got {timeout}"
+                            ))
+                        })
+                })
+                .transpose()
+        };
+        let defaults = HttpOptions::default();
+        Ok(HttpOptions {
+            base_url: variable("AOC_BASE_URL").unwrap_or(defaults.base_url),
+            user_agent: variable("AOC_USER_AGENT").unwrap_or(defaults.user_agent),
+            timeout: timeout("AOC_TIMEOUT")?.unwrap_or(defaults.timeout),
+            connect_timeout: timeout("AOC_CONNECT_TIMEOUT")?.unwrap_or(defaults.connect_timeout),
+            proxy: variable("AOC_PROXY").or(defaults.proxy),
+        })
+    }
+
+    fn build_client(&self) -> Result<Client, OnlinePuzzleSourceCreateError> {
+        let mut builder = Client::builder()
+            .user_agent(&self.user_agent)
+            .timeout(self.timeout)
+            .connect_timeout(self.connect_timeout);
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(Proxy::all(proxy).map_err(|e| {
+                OnlinePuzzleSourceCreateError::InvalidHttpOptions(format!(
+                    "invalid proxy {proxy}: {e}"
+                ))
+            })?);
+        }
+        builder
+            .build()
+            .map_err(|e| OnlinePuzzleSourceCreateError::InvalidHttpOptions(e.to_string()))
+    }
+}
+
+/// Where the session token came from, in the order they are tried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// The `--token` argument
+    Argument,
+    /// The [TOKEN_VARIABLE] environment variable
+    Environment,
+    /// `token.txt` in the current directory
+    CurrentDirectoryFile(PathBuf),
+    /// `token.txt` at the root of the repo
+    RepoFile(PathBuf),
+    /// `token.txt` in the config directory of the app
+    ConfigFile(PathBuf),
+}
+
+impl Display for TokenSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenSource::Argument => write!(f, "the --token argument"),
+            TokenSource::Environment => write!(f, "{TOKEN_VARIABLE}"),
+            TokenSource::CurrentDirectoryFile(path)
+            | TokenSource::RepoFile(path)
+            | TokenSource::ConfigFile(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+struct Config {
+    api_token: String,
+    token_source: TokenSource,
+    /// The token as it was read, before trimming, which named the cache directory before
+    untrimmed_token: String,
+}
+
+/// Finds the first token set among the argument, the environment variable and the token files,
+/// skipping the empty ones.
+fn resolve_token(
+    argument: Option<&str>,
+    environment: Option<String>,
+    files: &[TokenSource],
+) -> Result<Config, OnlinePuzzleSourceCreateError> {
+    let found = |token: &str, token_source| {
+        let untrimmed_token = token.to_owned();
+        let token = token.trim();
+        (!token.is_empty()).then(|| Config {
+            api_token: token.to_owned(),
+            token_source,
+            untrimmed_token,
+        })
+    };
+    if let Some(config) = argument.and_then(|token| found(token, TokenSource::Argument)) {
+        return Ok(config);
+    }
+    if let Some(config) = environment.and_then(|token| found(&token, TokenSource::Environment)) {
+        return Ok(config);
+    }
+    for source in files {
+        let (TokenSource::CurrentDirectoryFile(path)
+        | TokenSource::RepoFile(path)
+        | TokenSource::ConfigFile(path)) = source
+        else {
+            continue;
+        };
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => {
+                return Err(OnlinePuzzleSourceCreateError::FailedToLoadToken {
+                    path: path.clone(),
+                    message: e.to_string(),
+                })
+            }
+        };
+        if let Some(config) = found(&contents, source.clone()) {
+            return Ok(config);
+        }
+    }
+    Err(OnlinePuzzleSourceCreateError::MissingToken(
+        files.iter().map(ToString::to_string).collect(),
+    ))
+}
+
+pub struct CachedOnlinePuzzleSource {
+    /// Everything cached for the token, which identifies the account and so the inputs
+    token_directory: PathBuf,
+    cache_directory: PathBuf,
+    config: Config,
+    /// Where the website is, without a trailing slash
+    base_url: String,
+    client: Client,
+}
+
+/// What is known about a cached input, stored next to it, to tell a corrupt one apart.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+struct InputMetadata {
+    /// When it was downloaded, in seconds since the Unix epoch
+    fetched_at: u64,
+    /// The HTTP status of the response
+    status: u16,
+    /// SHA-256 of the input, in hex
+    sha256: String,
+}
+
+/// Names the cache directory of a token, without storing the token itself.
+fn token_hash(token: &str) -> String {
+    hex_encode(&Sha256::digest(token.as_bytes())[..10])
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut output, b| {
+        let _ = write!(output, "{b:02x}");
+        output
+    })
+}
+
+impl CachedOnlinePuzzleSource {
+    fn new_in_directory(
+        cache_root: &Path,
+        config: Config,
+        http_options: HttpOptions,
+    ) -> Result<Self, OnlinePuzzleSourceCreateError> {
+        let token_directory = cache_root.join(token_hash(&config.api_token));
+        let cache_directory = token_directory.join("inputs");
+
+        // the token used to be hashed untrimmed, e.g. with the newline at the end of token.txt
+        let legacy_directory = cache_root.join(token_hash(&config.untrimmed_token));
+        if legacy_directory != token_directory
+            && legacy_directory.is_dir()
+            && !token_directory.exists()
+        {
+            log::info!(
+                "moving the cache of the token from {} to {}",
+                legacy_directory.display(),
+                token_directory.display()
+            );
+            if let Err(e) = std::fs::rename(&legacy_directory, &token_directory) {
+                log::warn!("failed to move {}: {e}", legacy_directory.display());
+            }
+        }
+
+        std::fs::create_dir_all(&cache_directory).map_err(|e| {
+            OnlinePuzzleSourceCreateError::FailedToCreateCacheDirectory {
+                path: cache_directory.clone(),
+                message: e.to_string(),
+            }
+        })?;
+
+        Ok(CachedOnlinePuzzleSource {
+            config,
+            token_directory,
+            cache_directory,
+            base_url: http_options.base_url.trim_end_matches('/').to_owned(),
+            client: http_options.build_client()?,
+        })
+    }
+
+    /// Caches the inputs in the cache directory of `app_name`, usually the binary's package name.
+    ///
+    /// The session token is `token` if given, or [TOKEN_VARIABLE] if set, or the contents of
+    /// `token.txt` in the current directory, in `repo_directory` if the binary runs in its repo,
+    /// or in the config directory of `app_name`. The requests use [HttpOptions::from_env].
+    pub fn new(
+        app_name: &str,
+        repo_directory: Option<&Path>,
+        token: Option<&str>,
+    ) -> Result<Self, OnlinePuzzleSourceCreateError> {
+        let project_dirs = ProjectDirs::from("", "", app_name).unwrap();
+        let current_directory = std::env::current_dir()?;
+        let files = [
+            Some(TokenSource::CurrentDirectoryFile(
+                current_directory.join(TOKEN_FILE),
+            )),
+            repo_directory
+                .filter(|&repo_directory| repo_directory != current_directory)
+                .map(|repo_directory| TokenSource::RepoFile(repo_directory.join(TOKEN_FILE))),
+            Some(TokenSource::ConfigFile(
+                project_dirs.config_dir().join(TOKEN_FILE),
+            )),
+        ];
+        let files = files.into_iter().flatten().collect::<Vec<_>>();
+        let config = resolve_token(token, std::env::var(TOKEN_VARIABLE).ok(), &files)?;
+        log::debug!("using the session token from {}", config.token_source);
+
+        Self::new_in_directory(project_dirs.cache_dir(), config, HttpOptions::from_env()?)
+    }
+
+    /// Makes the requests with `options` instead.
+    pub fn with_http_options(
+        self,
+        options: HttpOptions,
+    ) -> Result<Self, OnlinePuzzleSourceCreateError> {
+        Ok(CachedOnlinePuzzleSource {
+            base_url: options.base_url.trim_end_matches('/').to_owned(),
+            client: options.build_client()?,
+            ..self
+        })
+    }
+
+    /// Where the session token came from.
+    pub fn get_token_source(&self) -> &TokenSource {
+        &self.config.token_source
+    }
+
+    fn get_year_directory(&self, year: Year) -> PathBuf {
+        self.cache_directory.join(year.to_string())
+    }
+
+    fn get_day_path(&self, year: Year, day: Day) -> PathBuf {
+        self.get_year_directory(year)
+            .join(day.to_string())
+            .with_extension("txt")
+    }
+
+    fn get_metadata_path(&self, year: Year, day: Day) -> PathBuf {
+        self.get_year_directory(year)
+            .join(day.to_string())
+            .with_extension("meta.toml")
+    }
+
+    /// Reads a cached input, or why it's corrupt. Inputs cached before the metadata existed are
+    /// only checked for error pages.
+    fn read_cached(&self, year: Year, day: Day) -> Option<Result<String, String>> {
+        let contents = std::fs::read_to_string(self.get_day_path(year, day)).ok()?;
+        let metadata = match std::fs::read_to_string(self.get_metadata_path(year, day)) {
+            Ok(metadata) => match toml::from_str::<InputMetadata>(&metadata) {
+                Ok(metadata) => Some(metadata),
+                Err(e) => return Some(Err(format!("invalid metadata: {e}"))),
+            },
+            Err(_) => None,
+        };
+        if let Some(metadata) = metadata {
+            if hex_encode(&Sha256::digest(contents.as_bytes())) != metadata.sha256 {
+                return Some(Err("its SHA-256 doesn't match its metadata".to_owned()));
+            }
+        }
+        Some(
+            check_body(&contents)
+                .map(|()| contents)
+                .map_err(|e| e.to_string()),
+        )
+    }
+
+    /// The answers known for this token's inputs.
+    pub fn get_answer_store(&self) -> AnswerStore {
+        AnswerStore::new(self.token_directory.join("answers"))
+    }
+
+    /// The answers submitted with this token.
+    pub fn get_submission_history(&self) -> SubmissionHistory {
+        SubmissionHistory::new(self.token_directory.join("submissions"))
+    }
+
+    /// Reads an input only if it was already downloaded, and isn't corrupt.
+    pub fn get_cached_input(&self, year: Year, day: Day) -> Option<PuzzleInput> {
+        match self.read_cached(year, day)? {
+            Ok(contents) => Some(contents.as_str().into()),
+            Err(reason) => {
+                log::warn!("cached input for {year} day {day} is corrupt: {reason}");
+                None
+            }
+        }
+    }
+
+    /// Downloads an input, with the status of the response.
+    fn get_input_from_api(
+        &self,
+        year: Year,
+        day: Day,
+    ) -> Result<(String, u16), PuzzleInputApiError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header(COOKIE, format!("session={}", self.config.api_token))
+            .send()
+            .map_err(|e| PuzzleInputApiError::ApiError(e.to_string()))?;
+        let status = response.status();
+        let data = response
+            .text()
+            .map_err(|e| PuzzleInputApiError::ApiError(e.to_string()))?;
+
+        if !status.is_success() {
+            // the known error messages tell more than their status
+            return Err(match check_body(&data) {
+                Err(e @ (PuzzleInputApiError::NotUnlocked | PuzzleInputApiError::NotLoggedIn)) => e,
+                _ => PuzzleInputApiError::Status(status.as_u16()),
+            });
+        }
+        check_body(&data)?;
+        Ok((data, status.as_u16()))
+    }
+
+    /// Submits the answer of a part, and reads whether it was correct.
+    pub fn submit_answer(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<SubmitOutcome, PuzzleInputApiError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .client
+            .post(&url)
+            .header(COOKIE, format!("session={}", self.config.api_token))
+            .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .map_err(|e| PuzzleInputApiError::ApiError(e.to_string()))?;
+
+        Ok(parse_response(&response))
+    }
+
+    fn download_and_cache(&self, year: Year, day: Day) -> Result<String, PuzzleInputSaveError> {
+        let (data, status) = self.get_input_from_api(year, day)?;
+        let metadata = InputMetadata {
+            fetched_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            status,
+            sha256: hex_encode(&Sha256::digest(data.as_bytes())),
+        };
+
+        // the input only replaces the cached one once its metadata is written, so it's never left
+        // without the metadata that checks it, and the old input doesn't match the new metadata
+        std::fs::create_dir_all(self.get_year_directory(year))?;
+        let path = self.get_day_path(year, day);
+        let temporary_path = path.with_extension("txt.tmp");
+        std::fs::write(&temporary_path, &data)?;
+        let written = std::fs::write(
+            self.get_metadata_path(year, day),
+            toml::to_string_pretty(&metadata).map_err(std::io::Error::other)?,
+        )
+        .and_then(|()| std::fs::rename(&temporary_path, &path));
+        if written.is_err() {
+            let _ = std::fs::remove_file(&temporary_path);
+        }
+        written?;
+
+        Ok(data)
+    }
+}
+
+impl PuzzleSource for CachedOnlinePuzzleSource {
+    fn get_input(&self, year: Year, day: Day) -> Result<PuzzleInput, Box<dyn std::error::Error>> {
+        let path = self.get_day_path(year, day);
+
+        match self.read_cached(year, day) {
+            Some(Ok(contents)) => {
+                log::trace!(
+                    "reading cached input for {year} day {day} at {}",
+                    path.to_str().unwrap()
+                );
+                return Ok(contents.as_str().into());
+            }
+            Some(Err(reason)) => {
+                log::warn!("cached input for {year} day {day} is corrupt: {reason}");
+            }
+            None => {}
+        }
+
+        log::debug!(
+            "downloading input for {year} day {day} to {}",
+            path.to_str().unwrap()
+        );
+
+        let contents = self.download_and_cache(year, day)?;
+
+        Ok(contents.as_str().into())
+    }
+}
+
+const DAY_NOT_UNLOCKED_START: &str =
+    "Please don't repeatedly request this endpoint before it unlocks!";
+const NOT_LOGGED_IN: &str = "Please log in to get your puzzle input.";
+
+/// Rejects the bodies that are error messages or web pages rather than an input.
+fn check_body(body: &str) -> Result<(), PuzzleInputApiError> {
+    let trimmed = body.trim_start();
+    let start = trimmed.to_lowercase();
+    if trimmed.starts_with(DAY_NOT_UNLOCKED_START) {
+        Err(PuzzleInputApiError::NotUnlocked)
+    } else if body.contains(NOT_LOGGED_IN) {
+        Err(PuzzleInputApiError::NotLoggedIn)
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err(PuzzleInputApiError::InvalidInput("a web page"))
+    } else if trimmed.is_empty() {
+        Err(PuzzleInputApiError::InvalidInput("empty"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum OnlinePuzzleSourceCreateError {
+    #[error("failed to load token at {path}: {message}")]
+    FailedToLoadToken { path: PathBuf, message: String },
+    #[error("no session token, pass --token, set {TOKEN_VARIABLE} or write it to one of {}", .0.join(", "))]
+    MissingToken(Vec<String>),
+    #[error("invalid HTTP settings: {0}")]
+    InvalidHttpOptions(String),
+    #[error("failed to create cache directory {path}: {message}")]
+    FailedToCreateCacheDirectory { path: PathBuf, message: String },
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PuzzleInputApiError {
+    #[error("Advent of Code API returned an error: {0}")]
+    ApiError(String),
+    #[error("Puzzle not unlocked")]
+    NotUnlocked,
+    #[error("not logged in, the session token is invalid or expired")]
+    NotLoggedIn,
+    #[error("Advent of Code API returned status {0}")]
+    Status(u16),
+    #[error("the downloaded input is {0}")]
+    InvalidInput(&'static str),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PuzzleInputSaveError {
+    #[error("failed to download puzzle input: {0}")]
+    ApiError(#[from] PuzzleInputApiError),
+    #[error("failed to save puzzle input: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::Lines;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers one request with `status` and `body`, and gives back the request.
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (url, server)
+    }
+
+    fn test_source(directory: &Path, base_url: &str) -> CachedOnlinePuzzleSource {
+        let config = Config {
+            api_token: "secret".to_owned(),
+            token_source: TokenSource::Argument,
+            untrimmed_token: "secret".to_owned(),
+        };
+        let options = HttpOptions {
+            base_url: base_url.to_owned(),
+            ..HttpOptions::default()
+        };
+        CachedOnlinePuzzleSource::new_in_directory(directory, config, options).unwrap()
+    }
+
+    #[test]
+    fn test_legacy_token_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let legacy_directory = directory.path().join(token_hash("secret\n"));
+        std::fs::create_dir_all(legacy_directory.join("answers")).unwrap();
+        let config = Config {
+            api_token: "secret".to_owned(),
+            token_source: TokenSource::Argument,
+            untrimmed_token: "secret\n".to_owned(),
+        };
+        let source = CachedOnlinePuzzleSource::new_in_directory(
+            directory.path(),
+            config,
+            HttpOptions::default(),
+        )
+        .unwrap();
+        assert!(!legacy_directory.exists());
+        assert!(source.token_directory.join("answers").is_dir());
+        assert!(source.cache_directory.is_dir());
+    }
+
+    #[test]
+    fn test_download() {
+        let directory = tempfile::tempdir().unwrap();
+        let (url, server) = serve_once("200 OK", "3   4\n4   3\n");
+        let source = test_source(directory.path(), &url);
+
+        let input = source.get_input(2024, 1).unwrap();
+        assert_eq!(input.get_raw(), "3   4\n4   3\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("cookie: session=secret\r\n"));
+        assert!(request.contains(&format!("user-agent: {DEFAULT_USER_AGENT}\r\n")));
+        let metadata = std::fs::read_to_string(source.get_metadata_path(2024, 1)).unwrap();
+        let metadata: InputMetadata = toml::from_str(&metadata).unwrap();
+        assert_eq!(metadata.status, 200);
+        assert_eq!(
+            metadata.sha256,
+            hex_encode(&Sha256::digest(b"3   4\n4   3\n"))
+        );
+
+        // the server is gone, so this one comes from the cache
+        let input = source.get_input(2024, 1).unwrap();
+        assert_eq!(input.get_raw(), "3   4\n4   3\n");
+        assert!(source.get_cached_input(2024, 2).is_none());
+    }
+
+    #[test]
+    fn test_invalid_download() {
+        let directory = tempfile::tempdir().unwrap();
+        let download = |status, body| {
+            let (url, server) = serve_once(status, body);
+            let source = test_source(directory.path(), &url);
+            let result = source.download_and_cache(2024, 1);
+            server.join().unwrap();
+            assert!(source.get_cached_input(2024, 1).is_none());
+            match result {
+                Err(PuzzleInputSaveError::ApiError(e)) => e,
+                _ => panic!("the download should fail"),
+            }
+        };
+
+        assert!(matches!(
+            download(
+                "400 Bad Request",
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            PuzzleInputApiError::NotLoggedIn
+        ));
+        assert!(matches!(
+            download(
+                "404 Not Found",
+                "Please don't repeatedly request this endpoint before it unlocks! ...\n"
+            ),
+            PuzzleInputApiError::NotUnlocked
+        ));
+        assert!(matches!(
+            download("500 Internal Server Error", "<html>oops</html>\n"),
+            PuzzleInputApiError::Status(500)
+        ));
+        assert!(matches!(
+            download("200 OK", "<!DOCTYPE html>\n<html></html>"),
+            PuzzleInputApiError::InvalidInput(_)
+        ));
+        assert!(matches!(
+            download("200 OK", "\n  <html><body>oops</body></html>"),
+            PuzzleInputApiError::InvalidInput("a web page")
+        ));
+        assert!(matches!(
+            download("200 OK", ""),
+            PuzzleInputApiError::InvalidInput("empty")
+        ));
+        assert!(matches!(
+            download(
+                "404 Not Found",
+                "\nPlease don't repeatedly request this endpoint before it unlocks! ...\n"
+            ),
+            PuzzleInputApiError::NotUnlocked
+        ));
+    }
+
+    #[test]
+    fn test_failed_metadata_write() {
+        let directory = tempfile::tempdir().unwrap();
+        let (url, server) = serve_once("200 OK", "1\n2\n");
+        let source = test_source(directory.path(), &url);
+        // a directory where the metadata goes makes writing it fail
+        std::fs::create_dir_all(source.get_metadata_path(2024, 1)).unwrap();
+        assert!(source.download_and_cache(2024, 1).is_err());
+        server.join().unwrap();
+        assert!(!source.get_day_path(2024, 1).exists());
+        assert!(!source
+            .get_day_path(2024, 1)
+            .with_extension("txt.tmp")
+            .exists());
+    }
+
+    #[test]
+    fn test_corrupt_cache() {
+        let directory = tempfile::tempdir().unwrap();
+        let (url, server) = serve_once("200 OK", "1\n2\n");
+        let source = test_source(directory.path(), &url);
+        source.get_input(2024, 1).unwrap();
+        server.join().unwrap();
+
+        std::fs::write(source.get_day_path(2024, 1), "1\n").unwrap();
+        assert!(source.get_cached_input(2024, 1).is_none());
+        let (url, server) = serve_once("200 OK", "1\n2\n");
+        let source = test_source(directory.path(), &url);
+        assert_eq!(source.get_input(2024, 1).unwrap().get_raw(), "1\n2\n");
+        server.join().unwrap();
+        assert!(source.get_cached_input(2024, 1).is_some());
+
+        // inputs cached before the metadata are kept, unless they are error pages
+        std::fs::remove_file(source.get_metadata_path(2024, 1)).unwrap();
+        assert!(source.get_cached_input(2024, 1).is_some());
+        std::fs::write(source.get_day_path(2024, 1), "<html>500</html>").unwrap();
+        assert!(source.get_cached_input(2024, 1).is_none());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let directory = tempfile::tempdir().unwrap();
+        // accepts connections without ever answering
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let source = test_source(directory.path(), &url)
+            .with_http_options(HttpOptions {
+                base_url: url.clone(),
+                timeout: Some(Duration::from_millis(100)),
+                ..HttpOptions::default()
+            })
+            .unwrap();
+
+        assert!(source.get_input(2024, 1).is_err());
+        assert!(source.get_cached_input(2024, 1).is_none());
+    }
+
+    #[test]
+    fn test_http_options() {
+        let options = HttpOptions::from_variables(|name| match name {
+            "AOC_BASE_URL" => Some("http://localhost:8080".to_owned()),
+            "AOC_TIMEOUT" => Some("2.5".to_owned()),
+            "AOC_CONNECT_TIMEOUT" => Some("0".to_owned()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(
+            options,
+            HttpOptions {
+                base_url: "http://localhost:8080".to_owned(),
+                timeout: Some(Duration::from_millis(2500)),
+                connect_timeout: None,
+                ..HttpOptions::default()
+            }
+        );
+
+        let invalid =
+            HttpOptions::from_variables(|name| (name == "AOC_TIMEOUT").then(|| "soon".to_owned()));
+        assert!(matches!(
+            invalid,
+            Err(OnlinePuzzleSourceCreateError::InvalidHttpOptions(_))
+        ));
+        let proxy = HttpOptions {
+            proxy: Some("not a url".to_owned()),
+            ..HttpOptions::default()
+        };
+        assert!(proxy.build_client().is_err());
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let directory = tempfile::tempdir().unwrap();
+        let (url, server) = serve_once(
+            "200 OK",
+            "<article><p>That's the right answer!</p></article>",
+        );
+        let source = test_source(directory.path(), &format!("{url}/"));
+
+        let outcome = source.submit_answer(2024, 3, 2, &Answer::Integer(42));
+        assert_eq!(outcome.unwrap(), SubmitOutcome::Correct);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/3/answer "));
+        assert!(request.contains("cookie: session=secret\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=42"));
+    }
+
+    #[test]
+    fn test_resolve_token() {
+        let directory = tempfile::tempdir().unwrap();
+        let current_file = directory.path().join("current.txt");
+        let repo_file = directory.path().join("repo.txt");
+        let config_file = directory.path().join("config.txt");
+        let files = [
+            TokenSource::CurrentDirectoryFile(current_file.clone()),
+            TokenSource::RepoFile(repo_file.clone()),
+            TokenSource::ConfigFile(config_file.clone()),
+        ];
+        let resolve = |argument, environment: Option<&str>| {
+            resolve_token(argument, environment.map(str::to_owned), &files)
+                .map(|config| (config.api_token, config.token_source))
+        };
+
+        assert!(matches!(
+            resolve(None, None),
+            Err(OnlinePuzzleSourceCreateError::MissingToken(paths)) if paths.len() == 3
+        ));
+        std::fs::write(&config_file, "config\n").unwrap();
+        assert_eq!(
+            resolve(None, None).unwrap(),
+            ("config".to_owned(), TokenSource::ConfigFile(config_file))
+        );
+        // an empty file is skipped like a missing one
+        std::fs::write(&repo_file, "\n").unwrap();
+        assert_eq!(resolve(None, None).unwrap().0, "config");
+        std::fs::write(&repo_file, "repo\r\n").unwrap();
+        assert_eq!(
+            resolve(None, None).unwrap(),
+            ("repo".to_owned(), TokenSource::RepoFile(repo_file))
+        );
+        std::fs::write(&current_file, "current\n").unwrap();
+        assert_eq!(
+            resolve(None, None).unwrap(),
+            (
+                "current".to_owned(),
+                TokenSource::CurrentDirectoryFile(current_file)
+            )
+        );
+        assert_eq!(
+            resolve(None, Some("env")).unwrap(),
+            ("env".to_owned(), TokenSource::Environment)
+        );
+        assert_eq!(
+            resolve(Some("argument"), Some("env")).unwrap(),
+            ("argument".to_owned(), TokenSource::Argument)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use crate::answer::Answer;
+use crate::aoc::{Day, Part, Year};
+use itertools::{EitherOrBoth, Itertools};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Answers known to be correct for one account's inputs, stored as one TOML file per year.
+pub struct AnswerStore {
+    directory: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct StoredAnswers {
+    #[serde(default)]
+    answers: Vec<StoredAnswer>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredAnswer {
+    day: Day,
+    part: Part,
+    answer: Answer,
+}
+
+impl AnswerStore {
+    pub fn new(directory: PathBuf) -> Self {
+        AnswerStore { directory }
+    }
+
+    fn get_year_path(&self, year: Year) -> PathBuf {
+        self.directory.join(format!("{year}.toml"))
+    }
+
+    /// All the known answers of a year, keyed by day and part.
+    pub fn get_answers(
+        &self,
+        year: Year,
+    ) -> Result<HashMap<(Day, Part), Answer>, AnswerStoreError> {
+        let path = self.get_year_path(year);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let stored: StoredAnswers = toml::from_str(&contents)
+            .map_err(|error| AnswerStoreError::InvalidFile { path, error })?;
+        Ok(stored
+            .answers
+            .into_iter()
+            .map(|item| ((item.day, item.part), item.answer))
+            .collect())
+    }
+
+    pub fn get(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+    ) -> Result<Option<Answer>, AnswerStoreError> {
+        Ok(self.get_answers(year)?.remove(&(day, part)))
+    }
+
+    /// Records the answer of a part, replacing the one already known.
+    pub fn set(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: Answer,
+    ) -> Result<(), AnswerStoreError> {
+        let mut answers = self.get_answers(year)?;
+        answers.insert((day, part), answer);
+        let stored = StoredAnswers {
+            answers: answers
+                .into_iter()
+                .sorted_by_key(|(k, _)| *k)
+                .map(|((day, part), answer)| StoredAnswer { day, part, answer })
+                .collect(),
+        };
+        fs::create_dir_all(&self.directory)?;
+        fs::write(self.get_year_path(year), toml::to_string_pretty(&stored)?)?;
+        Ok(())
+    }
+}
+
+/// Describes how an answer differs from the expected one, line by line for grids.
+pub fn format_mismatch(expected: &Answer, actual: &Answer) -> String {
+    match (expected, actual) {
+        (Answer::Grid(expected), Answer::Grid(actual)) => {
+            let mut diff = String::from("grids differ:");
+            for row in expected.iter().zip_longest(actual) {
+                let line = match row {
+                    EitherOrBoth::Both(e, a) if e == a => format!("\n    {e}"),
+                    EitherOrBoth::Both(e, a) => format!("\n  - {e}\n  + {a}"),
+                    EitherOrBoth::Left(e) => format!("\n  - {e}"),
+                    EitherOrBoth::Right(a) => format!("\n  + {a}"),
+                };
+                diff.push_str(&line);
+            }
+            diff
+        }
+        _ => format!("expected {expected}, got {actual}"),
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AnswerStoreError {
+    #[error("invalid answers file {path}: {error}")]
+    InvalidFile {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    #[error("failed to serialize answers: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = AnswerStore::new(directory.path().join("answers"));
+        assert_eq!(store.get(2024, 1, 1).unwrap(), None);
+
+        store.set(2024, 1, 1, Answer::from(42)).unwrap();
+        store.set(2024, 1, 2, Answer::from("a,b")).unwrap();
+        store.set(2023, 1, 1, Answer::from(7)).unwrap();
+        store.set(2024, 1, 1, Answer::from(43)).unwrap();
+
+        assert_eq!(store.get(2024, 1, 1).unwrap(), Some(Answer::from(43)));
+        assert_eq!(store.get(2024, 1, 2).unwrap(), Some(Answer::from("a,b")));
+        assert_eq!(store.get(2023, 1, 1).unwrap(), Some(Answer::from(7)));
+        assert_eq!(store.get(2024, 2, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let directory = tempfile::tempdir().unwrap();
+        fs::write(directory.path().join("2024.toml"), "answers = 1").unwrap();
+        let store = AnswerStore::new(directory.path().to_path_buf());
+        assert!(matches!(
+            store.get_answers(2024),
+            Err(AnswerStoreError::InvalidFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_format_mismatch() {
+        assert_eq!(
+            format_mismatch(&Answer::from(1), &Answer::from(2)),
+            "expected 1, got 2"
+        );
+        let expected = Answer::Grid(vec!["#.".to_owned(), ".#".to_owned()]);
+        let actual = Answer::Grid(vec!["#.".to_owned(), "##".to_owned(), "..".to_owned()]);
+        assert_eq!(
+            format_mismatch(&expected, &actual),
+            "grids differ:\n    #.\n  - .#\n  + ##\n  + .."
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Harness for Advent of Code solutions: registering solvers, fetching and caching inputs,
+//! benchmarking, and grid utilities shared by the puzzles.
+//!
+//! Binaries using the benchmarks must register the allocator that measures memory usage:
+//! ```rust
+//! #[global_allocator]
+//! static ALLOCATOR: aoc_framework::bench::PeakAlloc = aoc_framework::bench::PeakAlloc;
+//! ```
+
+// lets the code generated by `#[aoc]` refer to this crate the same way inside and outside of it
+extern crate self as aoc_framework;
+
+pub mod answer;
+pub mod aoc;
+pub mod bench;
+pub mod context;
+pub mod external;
+pub mod inputs;
+pub mod known_answers;
+pub mod parsing;
+pub mod stress;
+pub mod submissions;
+pub mod submit;
+pub mod utils;
+pub mod visualize;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use linkme;
+}
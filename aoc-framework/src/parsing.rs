@@ -0,0 +1,380 @@
+use crate::aoc::{Lines, Params, ParseError, SolverContext};
+use regex::Captures;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// How a [PuzzleInput](crate::aoc::PuzzleInput) cleans up its text before the solvers read it.
+///
+/// The default converts line endings and trims every line, which suits most puzzles. Days where
+/// whitespace matters pick another one with `#[aoc(..., normalize = ...)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    /// Replaces `\r\n` line endings with `\n`.
+    pub unix_line_endings: bool,
+    /// What [Lines::get_lines] and [Lines::byte_lines] trim from every line.
+    pub trim: Trim,
+    /// What happens to the line breaks at the end of the input.
+    pub trailing_newline: TrailingNewline,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trim {
+    None,
+    End,
+    Both,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingNewline {
+    Keep,
+    /// Removes every line break at the end, leaving the input on its last non-empty line.
+    Strip,
+    /// Ends the input with exactly one line break.
+    Single,
+}
+
+impl Normalization {
+    pub const DEFAULT: Normalization = Normalization {
+        unix_line_endings: true,
+        trim: Trim::Both,
+        trailing_newline: TrailingNewline::Keep,
+    };
+
+    /// Leaves the input exactly as it was downloaded.
+    pub const RAW: Normalization = Normalization {
+        unix_line_endings: false,
+        trim: Trim::None,
+        trailing_newline: TrailingNewline::Keep,
+    };
+
+    pub const fn with_trim(mut self, trim: Trim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    pub const fn with_trailing_newline(mut self, trailing_newline: TrailingNewline) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Applies the options that change the text itself, only copying it if something changes.
+    pub(crate) fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+        if self.unix_line_endings && text.contains("\r\n") {
+            text = Cow::Owned(text.replace("\r\n", "\n"));
+        }
+        let end = text.trim_end_matches(['\r', '\n']).len();
+        match self.trailing_newline {
+            TrailingNewline::Keep => text,
+            TrailingNewline::Strip if end == text.len() => text,
+            TrailingNewline::Single if text[end..] == *"\n" => text,
+            TrailingNewline::Strip => Cow::Owned(text[..end].to_owned()),
+            TrailingNewline::Single => Cow::Owned(format!("{}\n", &text[..end])),
+        }
+    }
+
+    pub(crate) fn trim_line<'a>(&self, line: &'a str) -> &'a str {
+        match self.trim {
+            Trim::None => line,
+            Trim::End => line.trim_end(),
+            Trim::Both => line.trim(),
+        }
+    }
+
+    pub(crate) fn trim_bytes<'a>(&self, line: &'a [u8]) -> &'a [u8] {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        match self.trim {
+            Trim::None => line,
+            Trim::End => line.trim_ascii_end(),
+            Trim::Both => line.trim_ascii(),
+        }
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization::DEFAULT
+    }
+}
+
+/// A block of the input separated from the others by blank lines, see [Lines::sections].
+///
+/// Errors built from its lines point at their position in the whole input.
+pub struct Section<'a> {
+    pub(crate) source: &'a str,
+    pub(crate) text: &'a str,
+    pub(crate) params: &'a Params,
+    pub(crate) context: &'a SolverContext,
+    pub(crate) normalization: Normalization,
+}
+
+impl<'a> Section<'a> {
+    /// The text of the section, borrowed from the input rather than from the section.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+}
+
+impl Lines for Section<'_> {
+    fn get_raw(&self) -> &str {
+        self.text
+    }
+
+    fn get_params(&self) -> &Params {
+        self.params
+    }
+
+    fn get_context(&self) -> &SolverContext {
+        self.context
+    }
+
+    fn get_source(&self) -> &str {
+        self.source
+    }
+
+    fn get_normalization(&self) -> Normalization {
+        self.normalization
+    }
+}
+
+/// Builds a value from the groups of a regex match, see [Lines::captures_into].
+///
+/// ```rust,ignore
+/// impl FromCaptures for Robot {
+///     fn from_captures(captures: &Captured) -> Result<Self, ParseError> {
+///         Ok(Robot {
+///             p: XY { x: captures.get("px")?, y: captures.get("py")? },
+///             v: XY { x: captures.get("vx")?, y: captures.get("vy")? },
+///         })
+///     }
+/// }
+/// ```
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &Captured) -> Result<Self, ParseError>;
+}
+
+/// A regex match on the input.
+pub struct Captured<'a> {
+    pub(crate) source: &'a str,
+    pub(crate) captures: Captures<'a>,
+}
+
+impl<'a> Captured<'a> {
+    /// The text of the group named `name`.
+    pub fn text(&self, name: &str) -> Result<&'a str, ParseError> {
+        self.captures
+            .name(name)
+            .map(|group| group.as_str())
+            .ok_or_else(|| {
+                let matched = self.captures.get(0).map_or("", |m| m.as_str());
+                ParseError::at(self.source, matched, format!("missing {name}"))
+            })
+    }
+
+    /// Builds a [ParseError] pointing at `token`, which must be a slice of a group.
+    pub fn parse_error(&self, token: &str, message: impl Display) -> ParseError {
+        ParseError::at(self.source, token, message)
+    }
+
+    /// Parses the group named `name`.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        let text = self.text(name)?;
+        text.parse().map_err(|e| self.parse_error(text, e))
+    }
+}
+
+/// Splits `text` on blank lines, leaving out the blank lines and the line breaks around them.
+pub(crate) fn split_sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&text[start..end]);
+            }
+        } else {
+            start.get_or_insert(line_start);
+            end = line_start + line.trim_end_matches(['\r', '\n']).len();
+        }
+    }
+    if let Some(start) = start {
+        sections.push(&text[start..end]);
+    }
+    sections
+}
+
+/// Finds the integers in `text`, with their sign when a `-` comes right before them and isn't
+/// part of a word, like in `x=-3` but not in `3-4`.
+pub(crate) fn find_ints(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut ints = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        ints.push(&text[start..i]);
+    }
+    ints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::PuzzleInput;
+    use crate::utils::grid::DenseGrid;
+    use regex::Regex;
+
+    #[test]
+    fn test_normalization() {
+        let input = PuzzleInput::from("  a \r\n b\r\n\r\n");
+        assert_eq!(input.get_raw(), "  a \n b\n\n");
+        assert_eq!(input.get_lines().collect::<Vec<_>>(), vec!["a", "b", ""]);
+
+        let input = input.with_normalization(Normalization::RAW);
+        assert_eq!(input.get_raw(), "  a \r\n b\r\n\r\n");
+        assert_eq!(
+            input.get_lines().collect::<Vec<_>>(),
+            vec!["  a ", " b", ""]
+        );
+
+        let input = input.with_normalization(
+            Normalization::DEFAULT
+                .with_trim(Trim::End)
+                .with_trailing_newline(TrailingNewline::Strip),
+        );
+        assert_eq!(input.get_raw(), "  a \n b");
+        assert_eq!(input.get_lines().collect::<Vec<_>>(), vec!["  a", " b"]);
+
+        let single = Normalization::DEFAULT.with_trailing_newline(TrailingNewline::Single);
+        let input = input.with_normalization(single);
+        assert_eq!(input.get_raw(), "  a \n b\n");
+        assert_eq!(
+            PuzzleInput::from("x").with_normalization(single).get_raw(),
+            "x\n"
+        );
+    }
+
+    #[test]
+    fn test_byte_lines() {
+        let input = PuzzleInput::from("ab \r\n\n c").with_normalization(Normalization::RAW);
+        let lines = input.byte_lines().collect::<Vec<_>>();
+        assert_eq!(lines, vec![&b"ab "[..], b"", b" c"]);
+        assert_eq!(PuzzleInput::from("").byte_lines().count(), 0);
+        assert_eq!(PuzzleInput::from("a\n").byte_lines().count(), 1);
+
+        let input = PuzzleInput::from("12\n3x");
+        let error = input.byte_error(&input.get_bytes()[4..], "invalid");
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = PuzzleInput::from("a\nb\n\n\nc\r\n \nd\n");
+        let sections = input
+            .sections()
+            .map(|s| s.get_raw().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(sections, vec!["a\nb", "c", "d"]);
+    }
+
+    #[test]
+    fn test_section_error_position() {
+        let input = PuzzleInput::from("1\n2\n\n3\n-4");
+        let section = input.sections().nth(1).unwrap();
+        let error = section.ints::<u8>().map(|_| ()).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            find_ints("p=0,4 v=3,-3 a-5 3-4"),
+            vec!["0", "4", "3", "-3", "5", "3", "4"]
+        );
+
+        let input = PuzzleInput::from("190: 10 19\n\n3267: 81 40 27\n");
+        assert_eq!(
+            input.ints::<u64>(),
+            Ok(vec![vec![190, 10, 19], vec![3267, 81, 40, 27]])
+        );
+
+        let error = PuzzleInput::from("1 2\n3 -4").ints::<u8>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = PuzzleInput::from("ab\ncd\n");
+        let grid: DenseGrid<char> = input.grid().unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.at((1, 1)), Some(&'d'));
+
+        let error = PuzzleInput::from("ab\nc").grid::<char>().unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = PuzzleInput::from("12\n3x").grid::<Digit>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "invalid cell 'x'");
+    }
+
+    #[derive(Debug, Clone)]
+    struct Digit;
+
+    impl TryFrom<char> for Digit {
+        type Error = ();
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            value.is_ascii_digit().then_some(Digit).ok_or(())
+        }
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Move {
+        count: usize,
+        from: usize,
+    }
+
+    impl FromCaptures for Move {
+        fn from_captures(captures: &Captured) -> Result<Self, ParseError> {
+            Ok(Move {
+                count: captures.get("count")?,
+                from: captures.get("from")?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_captures_into() {
+        let regex = Regex::new(r"move (?<count>\d+) from (?<from>\w+)").unwrap();
+        let input = PuzzleInput::from("move 1 from 2\nmove 3 from 4\n");
+        assert_eq!(
+            input.captures_into(&regex),
+            Ok(vec![Move { count: 1, from: 2 }, Move { count: 3, from: 4 }])
+        );
+
+        let error = PuzzleInput::from("move 1 from 2\nmove 3 from x")
+            .captures_into::<Move>(&regex)
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 13));
+    }
+}
@@ -0,0 +1,250 @@
+//! Comparing the variants of a part on random inputs, to find the inputs where they disagree or
+//! panic, and shrinking those inputs to something small enough to debug.
+
+use crate::answer::Answer;
+use crate::aoc::{DaySolver, Lines, Part, PuzzleInput};
+use itertools::Itertools;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+pub use fastrand::Rng;
+
+/// Builds a random input of about `size` elements, like lines or numbers, registered with
+/// `#[aoc(day = 9, generator)]`.
+///
+/// The inputs must be valid, but can set parameters like a smaller grid than the real one.
+pub type GenerateFn = fn(&mut Rng, usize) -> PuzzleInput;
+
+#[derive(Debug, Clone, Copy)]
+pub struct StressOptions {
+    /// How many inputs to try
+    pub runs: usize,
+    pub size: usize,
+    /// Seed of the first input, the next ones using the following seeds
+    pub seed: u64,
+}
+
+/// What a variant gave on an input.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Answer(Answer),
+    Error(String),
+    Panic(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Error(error) => write!(f, "failed: {error}"),
+            Outcome::Panic(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// A generated input where the variants disagree or one of them panics.
+pub struct StressFailure {
+    pub seed: u64,
+    /// The smallest input found that still fails, with the parameters of the generated one
+    pub input: PuzzleInput,
+    /// What each variant gave on `input`
+    pub outcomes: Vec<(&'static str, Outcome)>,
+}
+
+/// Runs every variant of a part on `input`, catching their panics.
+///
+/// Panics still go through the panic hook, which prints them unless it's replaced.
+pub fn run_variants(
+    solver: &DaySolver,
+    input: &PuzzleInput,
+    part: Part,
+) -> Vec<(&'static str, Outcome)> {
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| solver.parse(input)))
+        .map_err(|panic| panic_message(&*panic));
+    solver
+        .variants(part)
+        .into_iter()
+        .map(|variant| {
+            let outcome = match &parsed {
+                Err(message) => Outcome::Panic(format!("in the parser: {message}")),
+                Ok(Err(e)) => Outcome::Error(e.to_string()),
+                Ok(Ok(parsed)) => match panic::catch_unwind(AssertUnwindSafe(|| {
+                    solver.solve_variant(input, parsed, part, variant)
+                })) {
+                    Ok(Ok(answer)) => Outcome::Answer(answer),
+                    Ok(Err(e)) => Outcome::Error(e.to_string()),
+                    Err(panic) => Outcome::Panic(panic_message(&*panic)),
+                },
+            };
+            (variant, outcome)
+        })
+        .collect()
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+/// How the variants fail, if they do: which ones panicked, and whether the others disagree.
+///
+/// Errors count as outcomes, so that variants rejecting the same input agree.
+#[derive(Debug, Eq, PartialEq)]
+struct FailureKind {
+    panicked: Vec<&'static str>,
+    disagree: bool,
+}
+
+fn failure_kind(outcomes: &[(&'static str, Outcome)]) -> Option<FailureKind> {
+    let (panicked, others): (Vec<_>, Vec<_>) = outcomes
+        .iter()
+        .partition(|(_, outcome)| matches!(outcome, Outcome::Panic(_)));
+    let kind = FailureKind {
+        panicked: panicked.into_iter().map(|&(variant, _)| variant).collect(),
+        disagree: !others.into_iter().map(|(_, outcome)| outcome).all_equal(),
+    };
+    (kind.disagree || !kind.panicked.is_empty()).then_some(kind)
+}
+
+/// Tries `options.runs` generated inputs, and minimizes the first one that fails.
+///
+/// Returns `None` if the day has no generator.
+pub fn stress(
+    solver: &DaySolver,
+    part: Part,
+    options: StressOptions,
+) -> Option<Result<(), StressFailure>> {
+    for seed in (options.seed..).take(options.runs) {
+        let input = solver.generate(seed, options.size)?;
+        if let Some(kind) = failure_kind(&run_variants(solver, &input, part)) {
+            // smaller inputs have to fail the same way, not just break another variant
+            let fails = |text: &str| {
+                let input =
+                    solver.prepare(PuzzleInput::from(text).with_params(input.params().clone()));
+                failure_kind(&run_variants(solver, &input, part)).as_ref() == Some(&kind)
+            };
+            let text = minimize(input.get_raw(), fails);
+            let input = solver.prepare(PuzzleInput::from(text).with_params(input.params().clone()));
+            return Some(Err(StressFailure {
+                seed,
+                outcomes: run_variants(solver, &input, part),
+                input,
+            }));
+        }
+    }
+    Some(Ok(()))
+}
+
+/// Removes as many lines, then characters, from `text` as possible while it `fails`.
+pub fn minimize(text: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let lines = text.split_inclusive('\n').collect_vec();
+    let lines = minimize_units(lines, |lines| fails(&lines.concat()));
+    let chars = lines.concat().chars().collect_vec();
+    let chars = minimize_units(chars, |chars| fails(&chars.iter().collect::<String>()));
+    chars.into_iter().collect()
+}
+
+/// Removes chunks of units, starting with halves and down to single units, for as long as it
+/// finds some to remove.
+fn minimize_units<T: Clone>(mut units: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunk = units.len().div_ceil(2);
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if fails(&candidate) {
+                units = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed {
+            chunk /= 2;
+        }
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize() {
+        let text = "a\nb\nxy\nc\nz\n";
+        // fails as long as there is an x and a z
+        let minimized = minimize(text, |text| text.contains('x') && text.contains('z'));
+        assert_eq!(minimized, "xz");
+
+        assert_eq!(minimize("abc", |_| false), "abc");
+    }
+
+    fn generate_lines(random: &mut Rng, size: usize) -> PuzzleInput {
+        (0..size)
+            .map(|_| format!("{}\n", random.u8(0..10)))
+            .collect::<String>()
+            .into()
+    }
+
+    #[test]
+    fn test_stress() {
+        let solver = DaySolver::builder()
+            .part(1, "sum", |input: &PuzzleInput| {
+                input
+                    .get_lines()
+                    .map(|line| line.parse::<u64>().unwrap())
+                    .sum::<u64>()
+            })
+            // wrong as soon as there is a 7
+            .part(1, "buggy", |input: &PuzzleInput| {
+                input
+                    .get_lines()
+                    .map(|line| line.parse::<u64>().unwrap())
+                    .filter(|&n| n != 7)
+                    .sum::<u64>()
+            })
+            .part(2, "panics", |input: &PuzzleInput| -> u64 {
+                assert!(!input.get_raw().contains('3'), "found a 3");
+                0
+            })
+            .generator(generate_lines)
+            .build();
+        let options = StressOptions {
+            runs: 20,
+            size: 20,
+            seed: 0,
+        };
+
+        let failure = stress(&solver, 1, options).unwrap().unwrap_err();
+        assert_eq!(failure.input.get_raw(), "7");
+        assert_eq!(
+            failure.outcomes,
+            vec![
+                ("sum", Outcome::Answer(Answer::Integer(7))),
+                ("buggy", Outcome::Answer(Answer::Integer(0)))
+            ]
+        );
+        // the same seed gives the same input
+        let input = solver.generate(1, 20).unwrap();
+        assert_eq!(input.get_raw(), solver.generate(1, 20).unwrap().get_raw());
+
+        let failure = stress(&solver, 2, options).unwrap().unwrap_err();
+        assert_eq!(failure.input.get_raw(), "3");
+        assert_eq!(
+            failure.outcomes,
+            vec![("panics", Outcome::Panic("found a 3".to_owned()))]
+        );
+
+        let solver = DaySolver::builder()
+            .part(1, "sum", |_: &PuzzleInput| 0)
+            .build();
+        assert!(stress(&solver, 1, options).is_none());
+    }
+}
@@ -0,0 +1,288 @@
+use crate::answer::Answer;
+use crate::aoc::{Day, Part, Year};
+use crate::submit::{Hint, SubmitOutcome};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Every answer submitted for one account's inputs, stored as one TOML file per year, to avoid
+/// submitting the same wrong answer twice.
+pub struct SubmissionHistory {
+    directory: PathBuf,
+}
+
+/// What the website said about an answer, without the waiting times.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptResult {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer wasn't checked, it can be submitted again
+    NotChecked,
+}
+
+impl From<&SubmitOutcome> for AttemptResult {
+    fn from(outcome: &SubmitOutcome) -> Self {
+        match outcome {
+            SubmitOutcome::Correct => AttemptResult::Correct,
+            SubmitOutcome::Wrong { hint: None, .. } => AttemptResult::Wrong,
+            SubmitOutcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                ..
+            } => AttemptResult::TooHigh,
+            SubmitOutcome::Wrong {
+                hint: Some(Hint::TooLow),
+                ..
+            } => AttemptResult::TooLow,
+            SubmitOutcome::RateLimited(_)
+            | SubmitOutcome::WrongLevel
+            | SubmitOutcome::Unknown(_) => AttemptResult::NotChecked,
+        }
+    }
+}
+
+impl AttemptResult {
+    fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            AttemptResult::Wrong | AttemptResult::TooHigh | AttemptResult::TooLow
+        )
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: Day,
+    pub part: Part,
+    pub result: AttemptResult,
+    /// When it was submitted, in seconds since the Unix epoch
+    pub submitted_at: u64,
+    pub answer: Answer,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct StoredAttempts {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+/// What the earlier attempts say about an answer before submitting it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AnswerCheck {
+    /// Nothing is known against it
+    Unknown,
+    /// The same answer was already submitted, with this result
+    AlreadySubmitted(AttemptResult),
+    /// It can't be right given the answers that were too low or too high
+    OutOfBounds {
+        /// The largest answer that was too low
+        above: Option<i128>,
+        /// The smallest answer that was too high
+        below: Option<i128>,
+    },
+}
+
+impl Display for AnswerCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerCheck::Unknown => write!(f, "not submitted yet"),
+            AnswerCheck::AlreadySubmitted(AttemptResult::Correct) => {
+                write!(f, "already submitted, it was correct")
+            }
+            AnswerCheck::AlreadySubmitted(AttemptResult::TooHigh) => {
+                write!(f, "already submitted, it was too high")
+            }
+            AnswerCheck::AlreadySubmitted(AttemptResult::TooLow) => {
+                write!(f, "already submitted, it was too low")
+            }
+            AnswerCheck::AlreadySubmitted(_) => write!(f, "already submitted, it was wrong"),
+            AnswerCheck::OutOfBounds { above, below } => {
+                let bounds = [
+                    above.map(|above| format!("above {above}")),
+                    below.map(|below| format!("below {below}")),
+                ];
+                let bounds = bounds.into_iter().flatten().collect::<Vec<_>>();
+                write!(f, "the answer should be {}", bounds.join(" and "))
+            }
+        }
+    }
+}
+
+impl SubmissionHistory {
+    pub fn new(directory: PathBuf) -> Self {
+        SubmissionHistory { directory }
+    }
+
+    fn get_year_path(&self, year: Year) -> PathBuf {
+        self.directory.join(format!("{year}.toml"))
+    }
+
+    fn get_year_attempts(&self, year: Year) -> Result<Vec<Attempt>, SubmissionHistoryError> {
+        let path = self.get_year_path(year);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+        let stored: StoredAttempts = toml::from_str(&contents)
+            .map_err(|error| SubmissionHistoryError::InvalidFile { path, error })?;
+        Ok(stored.attempts)
+    }
+
+    /// The attempts of a part, oldest first.
+    pub fn get_attempts(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+    ) -> Result<Vec<Attempt>, SubmissionHistoryError> {
+        let mut attempts = self.get_year_attempts(year)?;
+        attempts.retain(|attempt| attempt.day == day && attempt.part == part);
+        Ok(attempts)
+    }
+
+    /// Records what the website said about an answer.
+    pub fn record(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: Answer,
+        outcome: &SubmitOutcome,
+    ) -> Result<(), SubmissionHistoryError> {
+        let mut attempts = self.get_year_attempts(year)?;
+        attempts.push(Attempt {
+            day,
+            part,
+            answer,
+            result: outcome.into(),
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+        });
+        let stored = StoredAttempts { attempts };
+        fs::create_dir_all(&self.directory)?;
+        fs::write(self.get_year_path(year), toml::to_string_pretty(&stored)?)?;
+        Ok(())
+    }
+
+    /// Compares an answer with the earlier attempts of its part.
+    pub fn check(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<AnswerCheck, SubmissionHistoryError> {
+        let attempts = self.get_attempts(year, day, part)?;
+        Ok(check_attempts(&attempts, answer))
+    }
+}
+
+fn check_attempts(attempts: &[Attempt], answer: &Answer) -> AnswerCheck {
+    let known = attempts
+        .iter()
+        .filter(|attempt| attempt.answer == *answer)
+        .find(|attempt| attempt.result.is_wrong() || attempt.result == AttemptResult::Correct);
+    if let Some(attempt) = known {
+        return AnswerCheck::AlreadySubmitted(attempt.result);
+    }
+
+    let Some(value) = answer.as_integer() else {
+        return AnswerCheck::Unknown;
+    };
+    let hinted = |result| {
+        attempts
+            .iter()
+            .filter(move |attempt| attempt.result == result)
+            .filter_map(|attempt| attempt.answer.as_integer())
+    };
+    let above = hinted(AttemptResult::TooLow).max();
+    let below = hinted(AttemptResult::TooHigh).min();
+    if above.is_some_and(|above| value <= above) || below.is_some_and(|below| value >= below) {
+        AnswerCheck::OutOfBounds { above, below }
+    } else {
+        AnswerCheck::Unknown
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SubmissionHistoryError {
+    #[error("invalid submissions file {path}: {error}")]
+    InvalidFile {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    #[error("failed to serialize submissions: {0}")]
+    Serialize(#[from] toml::ser::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn wrong(hint: Option<Hint>) -> SubmitOutcome {
+        SubmitOutcome::Wrong {
+            hint,
+            wait: Some(Duration::from_secs(60)),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let directory = tempfile::tempdir().unwrap();
+        let history = SubmissionHistory::new(directory.path().join("submissions"));
+        let check = |answer| history.check(2024, 1, 1, &Answer::from(answer)).unwrap();
+        assert_eq!(check(50), AnswerCheck::Unknown);
+
+        history
+            .record(2024, 1, 1, Answer::from(10), &wrong(Some(Hint::TooLow)))
+            .unwrap();
+        history
+            .record(2024, 1, 1, Answer::from(90), &wrong(Some(Hint::TooHigh)))
+            .unwrap();
+        history
+            .record(2024, 1, 1, Answer::from(40), &wrong(None))
+            .unwrap();
+        let rate_limited = SubmitOutcome::RateLimited(Duration::from_secs(30));
+        history
+            .record(2024, 1, 1, Answer::from(50), &rate_limited)
+            .unwrap();
+        // other parts have their own attempts
+        history
+            .record(2024, 1, 2, Answer::from(60), &wrong(None))
+            .unwrap();
+
+        assert_eq!(
+            check(40),
+            AnswerCheck::AlreadySubmitted(AttemptResult::Wrong)
+        );
+        assert_eq!(check(90).to_string(), "already submitted, it was too high");
+        assert_eq!(check(50), AnswerCheck::Unknown);
+        assert_eq!(check(60), AnswerCheck::Unknown);
+        let out_of_bounds = check(5);
+        assert_eq!(
+            out_of_bounds,
+            AnswerCheck::OutOfBounds {
+                above: Some(10),
+                below: Some(90)
+            }
+        );
+        assert_eq!(
+            out_of_bounds.to_string(),
+            "the answer should be above 10 and below 90"
+        );
+        assert_eq!(history.get_attempts(2024, 1, 1).unwrap().len(), 4);
+        assert_eq!(
+            history.check(2024, 1, 1, &Answer::from("text")).unwrap(),
+            AnswerCheck::Unknown
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! What the website answers when submitting an answer, see
+//! [CachedOnlinePuzzleSource::submit_answer].
+//!
+//! [CachedOnlinePuzzleSource::submit_answer]: crate::inputs::CachedOnlinePuzzleSource::submit_answer
+
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Whether a wrong answer was too high or too low, when the website says it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// How long to wait before the next answer is accepted
+        wait: Option<Duration>,
+    },
+    /// The previous answer was too recent, the answer wasn't checked
+    RateLimited(Duration),
+    /// The part was already solved, or isn't unlocked yet
+    WrongLevel,
+    /// A message that isn't recognized, as text
+    Unknown(String),
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::Wrong { hint, wait } => {
+                write!(f, "wrong")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", too high")?,
+                    Some(Hint::TooLow) => write!(f, ", too low")?,
+                    None => {}
+                }
+                if let Some(wait) = wait {
+                    write!(f, ", wait {} before trying again", format_wait(*wait))?;
+                }
+                Ok(())
+            }
+            SubmitOutcome::RateLimited(wait) => {
+                write!(f, "answered too recently, wait {}", format_wait(*wait))
+            }
+            SubmitOutcome::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+            SubmitOutcome::Unknown(message) => write!(f, "unknown response: {message}"),
+        }
+    }
+}
+
+fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{seconds}s"),
+        (minutes, 0) => format!("{minutes}m"),
+        (minutes, seconds) => format!("{minutes}m {seconds}s"),
+    }
+}
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article>(.*?)</article>").unwrap());
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+static TIME_LEFT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"you have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+static WAIT_MINUTES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap());
+
+/// Reads the outcome from the page returned by the answer endpoint.
+pub fn parse_response(html: &str) -> SubmitOutcome {
+    // the message is the only article of the page, the rest is the layout of the site
+    let message = ARTICLE
+        .captures(html)
+        .map_or(html, |captures| captures.get(1).unwrap().as_str());
+    let message = TAG.replace_all(message, "");
+    let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lowercase = message.to_lowercase();
+
+    if lowercase.contains("that's the right answer") {
+        SubmitOutcome::Correct
+    } else if lowercase.contains("that's not the right answer") {
+        let hint = if lowercase.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if lowercase.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = WAIT_MINUTES.captures(&lowercase).map(|captures| {
+            let minutes = match &captures[1] {
+                "one" => 1,
+                minutes => minutes.parse().unwrap(),
+            };
+            Duration::from_secs(minutes * 60)
+        });
+        SubmitOutcome::Wrong { hint, wait }
+    } else if lowercase.contains("you gave an answer too recently") {
+        let wait = TIME_LEFT.captures(&lowercase).map_or(0, |captures| {
+            let minutes = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let seconds: u64 = captures[2].parse().unwrap();
+            minutes * 60 + seconds
+        });
+        SubmitOutcome::RateLimited(Duration::from_secs(wait))
+    } else if lowercase.contains("you don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else {
+        SubmitOutcome::Unknown(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            SubmitOutcome::Correct
+        );
+        let wrong = parse_response(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+             you're using the full input data.  Please wait one minute before trying again. \
+             <a href=\"/2024/day/1\">[Return to Day 1]</a>",
+        ));
+        assert_eq!(
+            wrong,
+            SubmitOutcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            wrong.to_string(),
+            "wrong, too high, wait 1m before trying again"
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer.  Because you have guessed incorrectly 4 times on \
+                 this puzzle, please wait 5 minutes before trying again."
+            )),
+            SubmitOutcome::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        let rate_limited = parse_response(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 1m 5s left to wait.",
+        ));
+        assert_eq!(
+            rate_limited,
+            SubmitOutcome::RateLimited(Duration::from_secs(65))
+        );
+        assert_eq!(
+            rate_limited.to_string(),
+            "answered too recently, wait 1m 5s"
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            parse_response("<p>Something\nelse</p>"),
+            SubmitOutcome::Unknown("Something else".to_owned())
+        );
+    }
+}
@@ -1,2 +1,2 @@
-pub mod grid;
 mod flood;
+pub mod grid;
@@ -96,6 +96,18 @@ impl<T: Clone> DenseGrid<T> {
     pub fn height(&self) -> i64 {
         self.height
     }
+
+    /// Draws the grid one character per cell, e.g. to explain an answer with a path over it.
+    pub fn render(&self, cell: impl Fn(XY, &T) -> char) -> String {
+        self.items()
+            .chunks(self.width as usize)
+            .into_iter()
+            .map(|row| {
+                row.map(|(position, value)| cell(position, value))
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
 }
 
 impl<T: Eq> DenseGrid<T> {
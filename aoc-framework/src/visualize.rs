@@ -0,0 +1,253 @@
+//! Frames that the solvers of simulations record on their [SolverContext], played in the
+//! terminal or saved as an animated GIF by the `visualize` command.
+//!
+//! [SolverContext]: crate::context::SolverContext
+
+use crate::utils::grid::DenseGrid;
+use std::fmt::Write as _;
+use std::io::Write;
+
+/// The colours of the cells, from the ANSI palette so that both outputs look the same.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Color {
+    /// The colour of the terminal, light grey in GIFs, or the background for `.` and spaces
+    Default,
+    Gray,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+const COLORS: [Color; 9] = [
+    Color::Default,
+    Color::Gray,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+const BACKGROUND: [u8; 3] = [0x1e, 0x1e, 0x1e];
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Default => 39,
+            Color::Gray => 90,
+            Color::Red => 91,
+            Color::Green => 92,
+            Color::Yellow => 93,
+            Color::Blue => 94,
+            Color::Magenta => 95,
+            Color::Cyan => 96,
+            Color::White => 97,
+        }
+    }
+
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Default => [0xc0, 0xc0, 0xc0],
+            Color::Gray => [0x60, 0x60, 0x60],
+            Color::Red => [0xf0, 0x50, 0x50],
+            Color::Green => [0x50, 0xd0, 0x50],
+            Color::Yellow => [0xf0, 0xd0, 0x40],
+            Color::Blue => [0x50, 0x80, 0xf0],
+            Color::Magenta => [0xd0, 0x60, 0xd0],
+            Color::Cyan => [0x40, 0xd0, 0xd0],
+            Color::White => [0xff, 0xff, 0xff],
+        }
+    }
+
+    /// Index in the GIF palette, which starts with the background.
+    fn palette_index(self) -> u8 {
+        COLORS.iter().position(|&color| color == self).unwrap() as u8 + 1
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Cell {
+    fn is_background(&self) -> bool {
+        self.color == Color::Default && matches!(self.symbol, '.' | ' ')
+    }
+}
+
+pub type Frame = DenseGrid<Cell>;
+
+/// Draws a grid as a frame, picking the symbol and colour of each of its cells.
+pub fn frame<T: Clone>(grid: &DenseGrid<T>, cell: impl Fn(&T) -> (char, Color)) -> Frame {
+    DenseGrid::from_iter(
+        grid.width() as usize,
+        grid.items().map(|(_, value)| {
+            let (symbol, color) = cell(value);
+            Cell { symbol, color }
+        }),
+    )
+}
+
+/// Draws a frame with ANSI colours, only switching colours between cells that need it.
+pub fn to_ansi(frame: &Frame) -> String {
+    let mut output = String::new();
+    for row in frame.rows() {
+        let mut color = Color::Default;
+        for cell in row {
+            if cell.color != color {
+                color = cell.color;
+                let _ = write!(output, "\x1b[{}m", color.ansi_code());
+            }
+            output.push(cell.symbol);
+        }
+        if color != Color::Default {
+            output.push_str("\x1b[0m");
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Writes frames as an animated GIF, with a square of `scale` pixels per cell.
+pub struct GifWriter<W: Write> {
+    encoder: Option<gif::Encoder<W>>,
+    writer: Option<W>,
+    size: (u16, u16),
+    scale: u16,
+    /// Delay between frames, in hundredths of a second
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(writer: W, scale: u16, fps: u16) -> Self {
+        GifWriter {
+            encoder: None,
+            writer: Some(writer),
+            size: (0, 0),
+            scale: scale.max(1),
+            delay: (100 / fps.max(1)).max(1),
+        }
+    }
+
+    /// Adds a frame, which must have the same size as the first one.
+    pub fn write(&mut self, frame: &Frame) -> Result<(), VisualizeError> {
+        let too_large = || VisualizeError::TooLarge(frame.width(), frame.height());
+        let width = u16::try_from(frame.width() * self.scale as i64).map_err(|_| too_large())?;
+        let height = u16::try_from(frame.height() * self.scale as i64).map_err(|_| too_large())?;
+
+        // the size of the GIF is only known with the first frame
+        if let Some(writer) = self.writer.take() {
+            let palette = [BACKGROUND]
+                .into_iter()
+                .chain(COLORS.map(Color::rgb))
+                .flatten()
+                .collect::<Vec<u8>>();
+            let mut encoder = gif::Encoder::new(writer, width, height, &palette)?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.encoder = Some(encoder);
+            self.size = (width, height);
+        }
+        if (width, height) != self.size {
+            return Err(VisualizeError::SizeChanged);
+        }
+
+        let scale = self.scale as usize;
+        let pixels = frame
+            .rows()
+            .flat_map(|row| {
+                let row = row
+                    .iter()
+                    .flat_map(|cell| {
+                        let index = if cell.is_background() {
+                            0
+                        } else {
+                            cell.color.palette_index()
+                        };
+                        std::iter::repeat_n(index, scale)
+                    })
+                    .collect::<Vec<u8>>();
+                std::iter::repeat_n(row, scale).flatten()
+            })
+            .collect::<Vec<u8>>();
+        let mut gif_frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        gif_frame.delay = self.delay;
+        self.encoder.as_mut().unwrap().write_frame(&gif_frame)?;
+        Ok(())
+    }
+
+    /// Ends the GIF, and gives back the writer if it had at least one frame.
+    pub fn finish(self) -> Result<Option<W>, VisualizeError> {
+        match self.encoder {
+            Some(encoder) => Ok(Some(encoder.into_inner()?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum VisualizeError {
+    #[error("a {0}x{1} frame is too large for a GIF")]
+    TooLarge(i64, i64),
+    #[error("the frames don't all have the same size")]
+    SizeChanged,
+    #[error("failed to write the GIF: {0}")]
+    Gif(#[from] gif::EncodingError),
+    #[error("failed to write the GIF: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_frame(robot: (i64, i64)) -> Frame {
+        let mut grid = DenseGrid::from_rows(vec![vec!['.'; 3]; 2]);
+        grid.set_at(robot, '@');
+        frame(&grid, |&symbol| match symbol {
+            '@' => (symbol, Color::Red),
+            _ => (symbol, Color::Default),
+        })
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        assert_eq!(to_ansi(&test_frame((1, 0))), ".\x1b[91m@\x1b[39m.\n...\n");
+        assert_eq!(to_ansi(&test_frame((2, 1))), "...\n..\x1b[91m@\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut gif = GifWriter::new(vec![], 2, 10);
+        gif.write(&test_frame((0, 0))).unwrap();
+        gif.write(&test_frame((1, 0))).unwrap();
+        let smaller = frame(&DenseGrid::from_rows(vec![vec!['.']]), |&c| {
+            (c, Color::Default)
+        });
+        assert!(matches!(
+            gif.write(&smaller),
+            Err(VisualizeError::SizeChanged)
+        ));
+        let bytes = gif.finish().unwrap().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(bytes.as_slice())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 10);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+
+        assert!(GifWriter::new(vec![], 2, 10).finish().unwrap().is_none());
+    }
+}
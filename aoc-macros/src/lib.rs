@@ -0,0 +1,335 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Expr, FnArg, Item, ItemConst, ItemFn, ReturnType, Type};
+
+/// Registers a function as the parser or as a part of a day.
+///
+/// ```rust,ignore
+/// #[aoc(day = 5, parser)]
+/// fn parse(input: &str) -> Result<Rules, ParseError> { ... }
+///
+/// #[aoc(day = 5, part = 1)]
+/// fn solve_part_1(rules: &Rules) -> u64 { ... }
+/// ```
+///
+/// The year defaults to the `YEAR` constant in scope, and can be set with `year = 2024`. A part
+/// registered several times has several variants, the first one in the file being the default.
+/// `normalize = Normalization::RAW` changes how the whole day reads its input, see
+/// `Normalization`.
+///
+/// The argument type decides what the function receives:
+/// - `&str` or `&[u8]`: the raw input
+/// - `impl Lines`, `&impl Lines` or `&PuzzleInput`: the input with its parameters
+/// - any other reference: the output of the day's parser, with `&[T]` accepting a `Vec<T>`
+///
+/// A second `&SolverContext` argument receives the context of the run, to report progress.
+///
+/// With `generator`, it builds random inputs for the `stress` command instead, from a `&mut Rng`
+/// and a size, returning the input as a `String` or a `PuzzleInput` with parameters.
+///
+/// On a `Metadata` constant, it describes the day instead:
+///
+/// ```rust,ignore
+/// #[aoc(day = 5)]
+/// const METADATA: Metadata = Metadata {
+///     title: "Print Queue",
+///     tags: &["sorting"],
+///     ..Metadata::EMPTY
+/// };
+/// ```
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut attributes = Attributes::default();
+    let attribute_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("year") {
+            attributes.year = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("day") {
+            attributes.day = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("part") {
+            attributes.part = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("parser") {
+            attributes.parser = true;
+        } else if meta.path.is_ident("generator") {
+            attributes.generator = true;
+        } else if meta.path.is_ident("normalize") {
+            attributes.normalize = Some(meta.value()?.parse()?);
+        } else {
+            return Err(
+                meta.error("expected `year`, `day`, `part`, `parser`, `generator` or `normalize`")
+            );
+        }
+        Ok(())
+    });
+    parse_macro_input!(args with attribute_parser);
+    let item = parse_macro_input!(item as Item);
+
+    let registration = match &item {
+        Item::Fn(function) => register(attributes, function),
+        Item::Const(constant) => register_metadata(attributes, constant),
+        _ => Err(syn::Error::new(
+            item.span(),
+            "expected a function or a `Metadata` constant",
+        )),
+    };
+    let registration = registration.unwrap_or_else(|e| e.to_compile_error());
+    quote! {
+        #item
+        #registration
+    }
+    .into()
+}
+
+#[derive(Default)]
+struct Attributes {
+    year: Option<Expr>,
+    day: Option<Expr>,
+    part: Option<Expr>,
+    parser: bool,
+    generator: bool,
+    normalize: Option<Expr>,
+}
+
+impl Attributes {
+    fn year_and_day(&self, span: Span) -> syn::Result<(TokenStream2, TokenStream2)> {
+        let year = self
+            .year
+            .as_ref()
+            .map_or_else(|| quote!(YEAR), |year| year.into_token_stream());
+        let day = self
+            .day
+            .as_ref()
+            .ok_or_else(|| syn::Error::new(span, "missing `day = ...`"))?;
+        Ok((year, day.into_token_stream()))
+    }
+}
+
+fn register_metadata(attributes: Attributes, constant: &ItemConst) -> syn::Result<TokenStream2> {
+    let (year, day) = attributes.year_and_day(constant.span())?;
+    if attributes.part.is_some()
+        || attributes.parser
+        || attributes.generator
+        || attributes.normalize.is_some()
+    {
+        return Err(syn::Error::new(
+            constant.span(),
+            "metadata only takes `year` and `day`",
+        ));
+    }
+    let name = &constant.ident;
+    let kind = quote!(::aoc_framework::aoc::RegistrationKind::Metadata(&#name));
+    Ok(registration(
+        year,
+        day,
+        quote!(::core::option::Option::None),
+        kind,
+    ))
+}
+
+fn register_generator(attributes: Attributes, function: &ItemFn) -> syn::Result<TokenStream2> {
+    let signature = &function.sig;
+    let name = &signature.ident;
+    let (year, day) = attributes.year_and_day(signature.span())?;
+    if attributes.part.is_some() || attributes.parser || attributes.normalize.is_some() {
+        return Err(syn::Error::new(
+            signature.span(),
+            "a generator only takes `year` and `day`",
+        ));
+    }
+    if signature.inputs.len() != 2 {
+        return Err(syn::Error::new(
+            signature.span(),
+            "expected a generator like `fn generate(random: &mut Rng, size: usize) -> String`",
+        ));
+    }
+    let kind = quote! {
+        ::aoc_framework::aoc::RegistrationKind::Generator(
+            |random: &mut ::aoc_framework::stress::Rng, size: usize|
+             -> ::aoc_framework::aoc::PuzzleInput {
+                ::core::convert::Into::into(#name(random, size))
+            },
+        )
+    };
+    Ok(registration(
+        year,
+        day,
+        quote!(::core::option::Option::None),
+        kind,
+    ))
+}
+
+fn register(attributes: Attributes, function: &ItemFn) -> syn::Result<TokenStream2> {
+    if attributes.generator {
+        return register_generator(attributes, function);
+    }
+    let signature = &function.sig;
+    let name = &signature.ident;
+    let (year, day) = attributes.year_and_day(signature.span())?;
+
+    let mut arguments = vec![];
+    for (i, argument) in signature.inputs.iter().enumerate() {
+        let FnArg::Typed(argument) = argument else {
+            return Err(syn::Error::new(
+                argument.span(),
+                "methods can't be registered",
+            ));
+        };
+        arguments.push(match i {
+            0 => get_argument(&argument.ty, attributes.parser)?,
+            1 if is_context(&argument.ty) => {
+                quote!(::aoc_framework::aoc::Lines::get_context(input))
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    argument.span(),
+                    "only the input and a `&SolverContext` can be passed",
+                ))
+            }
+        });
+    }
+    if arguments.is_empty() {
+        return Err(syn::Error::new(
+            signature.span(),
+            "expected an argument with the input",
+        ));
+    }
+    let normalization = match attributes.normalize {
+        Some(normalization) => quote!(::core::option::Option::Some(#normalization)),
+        None => quote!(::core::option::Option::None),
+    };
+
+    let kind = match (attributes.part, attributes.parser) {
+        (Some(part), false) => quote! {
+            ::aoc_framework::aoc::RegistrationKind::Part {
+                part: #part,
+                name: stringify!(#name),
+                solver: |input: &::aoc_framework::aoc::PuzzleInput,
+                         parsed: &::aoc_framework::aoc::Parsed|
+                 -> Result<::aoc_framework::answer::Answer, ::aoc_framework::aoc::SolveError> {
+                    ::aoc_framework::aoc::IntoAnswer::into_answer(#name(#(#arguments),*))
+                },
+            }
+        },
+        (None, true) => {
+            // parsers that can't fail return their output directly
+            let parsed = if returns_result(&signature.output) {
+                quote!(#name(#(#arguments),*).map_err(::aoc_framework::aoc::SolveError::from)?)
+            } else {
+                quote!(#name(#(#arguments),*))
+            };
+            quote! {
+                ::aoc_framework::aoc::RegistrationKind::Parser(
+                    |input: &::aoc_framework::aoc::PuzzleInput|
+                     -> Result<::aoc_framework::aoc::Parsed, ::aoc_framework::aoc::SolveError> {
+                        Ok(Box::new(#parsed))
+                    },
+                )
+            }
+        }
+        _ => {
+            return Err(syn::Error::new(
+                signature.span(),
+                "expected either `part = ...` or `parser`",
+            ))
+        }
+    };
+
+    Ok(registration(year, day, normalization, kind))
+}
+
+fn registration(
+    year: TokenStream2,
+    day: TokenStream2,
+    normalization: TokenStream2,
+    kind: TokenStream2,
+) -> TokenStream2 {
+    quote! {
+        const _: () = {
+            #[::aoc_framework::__private::linkme::distributed_slice(::aoc_framework::aoc::SOLVERS)]
+            #[linkme(crate = ::aoc_framework::__private::linkme)]
+            #[allow(unused_variables)]
+            static REGISTRATION: ::aoc_framework::aoc::Registration = ::aoc_framework::aoc::Registration {
+                year: #year,
+                day: #day,
+                line: line!(),
+                normalization: #normalization,
+                kind: #kind,
+            };
+        };
+    }
+}
+
+/// Builds the expression passed to the function, from `input` and `parsed`.
+fn get_argument(argument_type: &Type, parser: bool) -> syn::Result<TokenStream2> {
+    let Type::Reference(reference) = argument_type else {
+        return if let Type::ImplTrait(_) = argument_type {
+            Ok(quote!(input))
+        } else {
+            Err(syn::Error::new(
+                argument_type.span(),
+                "expected the input as a reference or `impl Lines`",
+            ))
+        };
+    };
+
+    match &*reference.elem {
+        Type::Path(path) if path.path.is_ident("str") => {
+            Ok(quote!(::aoc_framework::aoc::Lines::get_raw(input)))
+        }
+        Type::Slice(slice) if is_ident(&slice.elem, "u8") => {
+            Ok(quote!(::aoc_framework::aoc::Lines::get_bytes(input)))
+        }
+        Type::ImplTrait(_) => Ok(quote!(input)),
+        Type::Path(path)
+            if path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "PuzzleInput") =>
+        {
+            Ok(quote!(input))
+        }
+        _ if parser => Err(syn::Error::new(
+            argument_type.span(),
+            "a parser reads the input as `&str`, `&[u8]` or `impl Lines`",
+        )),
+        Type::Slice(slice) => {
+            let element = &slice.elem;
+            Ok(quote!(::aoc_framework::aoc::get_parsed::<Vec<#element>>(parsed)?.as_slice()))
+        }
+        parsed_type => Ok(quote!(::aoc_framework::aoc::get_parsed::<#parsed_type>(parsed)?)),
+    }
+}
+
+fn is_context(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => match &*reference.elem {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "SolverContext"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_ident(ty: &Type, ident: &str) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident(ident))
+}
+
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
@@ -1,69 +1,105 @@
-use quote::quote;
+use quote::{format_ident, quote};
 use regex::Regex;
 use std::error::Error;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-fn get_solved_days(solutions_path: &Path) -> Result<Vec<usize>, Box<dyn Error>> {
-    let day_regex = Regex::new(r"^day(?<day>\d{2})\.rs$").unwrap();
-    std::fs::read_dir(solutions_path)?
-        .map(|entry_result| {
-            let entry = entry_result.map_err(|e| format!("Failed to read entry: {}", e))?;
-            let file_name_raw = entry.file_name();
-            let file_name = file_name_raw.to_string_lossy();
-            let captures = day_regex
-                .captures(&file_name)
-                .ok_or_else(|| format!("Invalid file in solution directory: {:?}", entry))?;
-            captures
-                .name("day")
-                .ok_or("Missing 'day' capture group")?
-                .as_str()
-                .parse::<usize>()
-                .map_err(|e| format!("Failed to parse day number: {}", e))
+/// Year, day and path of an examples file
+type ExampleFile = (u32, usize, PathBuf);
+
+/// Finds every `y{year}/day{day:02}.toml` file, sorted by year and day
+fn get_example_files(examples_path: &Path) -> Result<Vec<ExampleFile>, Box<dyn Error>> {
+    let year_regex = Regex::new(r"^y(?<year>\d{4})$").unwrap();
+    let day_regex = Regex::new(r"^day(?<day>\d{2})\.toml$").unwrap();
+    let mut files = vec![];
+    for year_entry in std::fs::read_dir(examples_path)? {
+        let year_entry = year_entry?;
+        let year_name = year_entry.file_name();
+        let year = year_regex
+            .captures(&year_name.to_string_lossy())
+            .ok_or_else(|| format!("Invalid file in examples directory: {year_entry:?}"))?["year"]
+            .parse::<u32>()?;
+        for day_entry in std::fs::read_dir(year_entry.path())? {
+            let day_entry = day_entry?;
+            let day_name = day_entry.file_name();
+            let day = day_regex
+                .captures(&day_name.to_string_lossy())
+                .ok_or_else(|| format!("Invalid file in examples directory: {day_entry:?}"))?
+                ["day"]
+                .parse::<usize>()?;
+            files.push((year, day, day_entry.path()));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Lists the parts that have an expected answer, for each example of the file
+fn get_example_parts(examples_path: &Path) -> Result<Vec<Vec<usize>>, Box<dyn Error>> {
+    let contents = std::fs::read_to_string(examples_path)?;
+    let table = contents
+        .parse::<toml::Table>()
+        .map_err(|e| format!("Invalid examples file {examples_path:?}: {e}"))?;
+    let examples = table
+        .get("example")
+        .and_then(toml::Value::as_array)
+        .ok_or_else(|| format!("Missing [[example]] entries in {examples_path:?}"))?;
+    examples
+        .iter()
+        .map(|example| {
+            let example = example
+                .as_table()
+                .ok_or_else(|| format!("Invalid example in {examples_path:?}"))?;
+            Ok([1, 2]
+                .into_iter()
+                .filter(|part| example.contains_key(&format!("part_{part}")))
+                .collect())
         })
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(From::from)
+        .collect()
 }
 
 fn main() {
-    println!("cargo::rerun-if-changed=src/solutions");
+    println!("cargo::rerun-if-changed=examples");
     println!("cargo::rerun-if-changed=build.rs");
 
-    let solutions_path = Path::new("src/solutions");
-    let solved_days = get_solved_days(solutions_path).unwrap();
+    let example_files = get_example_files(Path::new("examples")).unwrap();
 
     // Define the output file path (inside the `OUT_DIR` directory).
     let out_dir = std::env::var("OUT_DIR").unwrap();
-    let dest_path = Path::new(&out_dir).join("solved_days.rs");
+    let examples_code = generate_examples(example_files);
+    std::fs::write(Path::new(&out_dir).join("examples.rs"), examples_code)
+        .expect("Failed to write examples.rs");
+}
 
-    let generated_code = generate_static_hashmap(solved_days);
+fn generate_examples(example_files: Vec<ExampleFile>) -> String {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let mut files = vec![];
+    let mut tests = vec![];
+    for (year, day, path) in example_files {
+        let absolute_path = manifest_dir.join(&path).to_string_lossy().to_string();
+        files.push(quote! { (#year, #day, include_str!(#absolute_path)) });
 
-    // Write the generated code to the output file.
-    std::fs::write(&dest_path, generated_code).expect("Failed to write solved_days.rs");
-}
+        for (index, parts) in get_example_parts(&path).unwrap().into_iter().enumerate() {
+            for part in parts {
+                let test_name =
+                    format_ident!("y{year}_day{day:02}_example_{}_part_{part}", index + 1);
+                tests.push(quote! {
+                    #[test]
+                    fn #test_name() {
+                        super::assert_example(#year, #day, #index, #part);
+                    }
+                });
+            }
+        }
+    }
 
-fn generate_static_hashmap(days: Vec<usize>) -> String {
-    let hashmap_code = quote! {
-        use std::collections::HashMap;
-        use crate::aoc::{PuzzleInput, Solver, SolverMap};
+    let examples_code = quote! {
+        pub(crate) const EXAMPLE_FILES: &[(Year, Day, &str)] = &[#(#files),*];
 
-        pub fn get_solvers() -> SolverMap {
-            let mut map: SolverMap = HashMap::new();
-            #(map.insert(
-                (#days, 1),
-                Box::new(
-                    |input| <PuzzleInput as Solver<#days, 1>>::solve(input).map(|solution| solution.to_string())
-                )
-            );)*
-            #(map.insert(
-                (#days, 2),
-                Box::new(
-                    |input| <PuzzleInput as Solver<#days, 2>>::solve(input).map(|solution| solution.to_string())
-                )
-            );)*
-            map
+        #[cfg(test)]
+        mod example_tests {
+            #(#tests)*
         }
     };
 
-    // Format the generated code with prettyplease.
-    prettyplease::unparse(&syn::parse2(hashmap_code).unwrap())
+    prettyplease::unparse(&syn::parse2(examples_code).unwrap())
 }
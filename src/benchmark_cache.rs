@@ -1,6 +1,6 @@
-use crate::aoc::{Day, Part};
-use crate::bench::BenchmarkResults;
 use crate::BenchmarkMap;
+use aoc_framework::aoc::{Day, Part, Year};
+use aoc_framework::bench::BenchmarkResults;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::env::current_dir;
@@ -19,7 +19,7 @@ pub fn get_cached_benchmarks() -> Option<BenchmarkMap> {
         cached_value
             .benchmarks
             .into_iter()
-            .map(|item| ((item.day, item.part), item.result))
+            .map(|item| ((item.year, item.day, item.part), item.result))
             .collect(),
     )
 }
@@ -29,7 +29,8 @@ pub fn save_cached_benchmarks(cached_benchmarks: &BenchmarkMap) {
     let benchmarks = cached_benchmarks
         .iter()
         .sorted_by_key(|(k, _)| **k)
-        .map(|(&(day, part), v)| CachedItem {
+        .map(|(&(year, day, part), v)| CachedItem {
+            year,
             day,
             part,
             result: v.clone(),
@@ -46,6 +47,7 @@ struct CachedBenchmarks {
 
 #[derive(Serialize, Deserialize)]
 struct CachedItem {
+    year: Year,
     day: Day,
     part: Part,
     result: BenchmarkResults,
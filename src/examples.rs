@@ -0,0 +1,135 @@
+use aoc_framework::answer::Answer;
+use aoc_framework::aoc::{Day, DaySolver, Params, Part, PuzzleInput, SolveError, Year};
+use serde::Deserialize;
+
+// `EXAMPLE_FILES`, with the contents of every `examples/y{year}/day{day:02}.toml` file, and the
+// tests generated from them
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+#[derive(Debug, Deserialize)]
+struct ExampleFile {
+    example: Vec<Example>,
+}
+
+/// An example input from a puzzle description, with the expected answer of one or both parts.
+#[derive(Debug, Deserialize)]
+pub struct Example {
+    pub name: Option<String>,
+    input: String,
+    #[serde(default)]
+    params: toml::Table,
+    part_1: Option<ExpectedAnswer>,
+    part_2: Option<ExpectedAnswer>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum ExpectedAnswer {
+    Integer(i64),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl From<ExpectedAnswer> for Answer {
+    fn from(expected: ExpectedAnswer) -> Self {
+        match expected {
+            ExpectedAnswer::Integer(n) => Answer::Integer(n),
+            ExpectedAnswer::Text(s) => Answer::Text(s),
+            ExpectedAnswer::Grid(rows) => Answer::Grid(rows),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExampleError {
+    #[error("invalid examples file: {0}")]
+    InvalidFile(#[from] toml::de::Error),
+    #[error("got {actual}, expected {expected}")]
+    WrongAnswer { expected: Answer, actual: Answer },
+    #[error(transparent)]
+    Failed(#[from] SolveError),
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<Answer> {
+        match part {
+            1 => self.part_1.clone().map(Answer::from),
+            2 => self.part_2.clone().map(Answer::from),
+            _ => None,
+        }
+    }
+
+    pub fn input(&self) -> PuzzleInput {
+        let mut params = Params::default();
+        for (name, value) in &self.params {
+            match value {
+                toml::Value::String(s) => params.set(name, s),
+                value => params.set(name, value),
+            }
+        }
+        PuzzleInput::from(self.input.as_str()).with_params(params)
+    }
+
+    /// Solves `part` of the example with one of its variants, which fails if the answer doesn't
+    /// match.
+    ///
+    /// Returns `None` if the example doesn't have an expected answer for that part.
+    pub fn check(
+        &self,
+        solver: &DaySolver,
+        part: Part,
+        variant: &str,
+    ) -> Option<Result<Answer, ExampleError>> {
+        let expected = self.expected(part)?;
+        let input = solver.prepare(self.input());
+        let result = solver
+            .parse(&input)
+            .and_then(|parsed| solver.solve_variant(&input, &parsed, part, variant))
+            .map_err(ExampleError::from);
+        Some(result.and_then(|actual| {
+            if actual == expected {
+                Ok(actual)
+            } else {
+                Err(ExampleError::WrongAnswer { expected, actual })
+            }
+        }))
+    }
+}
+
+/// All the days that have an examples file.
+pub fn get_example_days() -> impl Iterator<Item = (Year, Day)> {
+    EXAMPLE_FILES.iter().map(|&(year, day, _)| (year, day))
+}
+
+pub fn get_examples(year: Year, day: Day) -> Result<Vec<Example>, ExampleError> {
+    EXAMPLE_FILES
+        .iter()
+        .find(|&&(y, d, _)| (y, d) == (year, day))
+        .map_or(Ok(vec![]), |(_, _, contents)| {
+            Ok(toml::from_str::<ExampleFile>(contents)?.example)
+        })
+}
+
+/// Runs an example through every variant of a part, used by the generated tests.
+#[cfg(test)]
+fn assert_example(year: Year, day: Day, index: usize, part: Part) {
+    let examples = get_examples(year, day).expect("failed to read examples");
+    let solvers = aoc_framework::aoc::get_solvers();
+    let solver = solvers
+        .get(&(year, day))
+        .unwrap_or_else(|| panic!("{year} day {day} is not solved"));
+    let variants = solver.variants(part);
+    if variants.is_empty() {
+        panic!("part {part} is not implemented");
+    }
+    for variant in variants {
+        match examples[index].check(solver, part, variant) {
+            Some(Ok(_)) => {}
+            Some(Err(ExampleError::Failed(SolveError::Parse(e)))) => {
+                panic!("{variant} failed to parse input: {e}")
+            }
+            Some(Err(e)) => panic!("{variant}: {e}"),
+            None => panic!("example has no answer for part {part}"),
+        }
+    }
+}
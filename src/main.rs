@@ -1,148 +1,1258 @@
-pub mod aoc;
-pub mod bench;
 mod benchmark_cache;
-pub mod inputs;
+pub mod examples;
 mod readme;
 pub mod solutions;
-pub mod utils;
 
-use crate::aoc::{get_days_iter, Day, Part, PuzzleSource, SolverMap};
-use crate::bench::{benchmark, BenchmarkResults};
 use crate::benchmark_cache::{get_cached_benchmarks, save_cached_benchmarks};
-use crate::inputs::CachedOnlinePuzzleSource;
-use crate::readme::update_readme;
-use crate::solutions::get_solvers;
-use clap::{arg, command, Command};
-use peak_alloc::PeakAlloc;
+use crate::examples::{get_example_days, get_examples};
+use crate::readme::{format_table, update_readme};
+use aoc_framework::answer::Answer;
+use aoc_framework::aoc::{
+    get_days_iter, get_solvers, Day, DaySolver, Lines, Params, Parsed, Part, PuzzleInput,
+    PuzzleSource, SolveError, SolverContext, SolverMap, Verbosity, Year,
+};
+use aoc_framework::bench::{
+    benchmark_after_first_run, benchmark_process_after_first_run, format_duration, BenchmarkError,
+    BenchmarkResults, PeakAlloc,
+};
+use aoc_framework::context::wait_for_abandoned_solvers;
+use aoc_framework::external::{load_external_solvers, register_external_solvers};
+use aoc_framework::inputs::CachedOnlinePuzzleSource;
+use aoc_framework::known_answers::format_mismatch;
+use aoc_framework::stress::{stress, StressOptions};
+use aoc_framework::submissions::AnswerCheck;
+use aoc_framework::submit::SubmitOutcome;
+use aoc_framework::visualize::{self, Frame, GifWriter};
+use clap::{arg, command, ArgAction, ArgMatches, Command};
+use itertools::Itertools;
+use rayon::prelude::*;
 use std::cell::LazyCell;
 use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
 use std::iter;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
 #[global_allocator]
-pub static PEAK_ALLOC: PeakAlloc = PeakAlloc;
+static ALLOCATOR: PeakAlloc = PeakAlloc;
 
-type BenchmarkMap = HashMap<(Day, Part), BenchmarkResults>;
+/// Solvers written in other languages, in the root of the repo, see [aoc_framework::external].
+const EXTERNAL_SOLVERS_FILE: &str = "external_solvers.toml";
+
+type BenchmarkMap = HashMap<(Year, Day, Part), BenchmarkResults>;
+
+/// How long the solvers that timed out get to notice that they were cancelled, before the next
+/// benchmark.
+const CANCELLED_SOLVER_GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct SolveOptions {
+    params: Params,
+    variant: Option<String>,
+    context: SolverContext,
+    timeout: Option<Duration>,
+    /// Print the explanations of the solvers under their answers
+    explain: bool,
+}
 
 fn main() -> Result<(), String> {
     pretty_env_logger::formatted_builder()
         .filter_level(log::LevelFilter::Debug)
         .init();
 
-    let puzzle_source = CachedOnlinePuzzleSource::new().expect("failed to configure puzzle source");
-    let solvers = get_solvers();
+    let repo_directory = find_repo_directory();
+    // solvers that time out keep running on their own thread, which needs them for good
+    let mut solvers = get_solvers();
+    let external_solvers_path = repo_directory
+        .as_deref()
+        .unwrap_or(Path::new("."))
+        .join(EXTERNAL_SOLVERS_FILE);
+    let external_solvers =
+        load_external_solvers(&external_solvers_path).map_err(|e| e.to_string())?;
+    register_external_solvers(&mut solvers, external_solvers).map_err(|e| e.to_string())?;
+    let solvers: &'static SolverMap = Box::leak(Box::new(solvers));
 
     let matches = command!()
+        .arg(
+            arg!(-v --verbose "show the debug output of the solvers")
+                .global(true)
+                .conflicts_with("quiet"),
+        )
+        .arg(arg!(-q --quiet "hide the progress of the solvers").global(true))
+        .arg(
+            arg!(--token <TOKEN> "session token to download the inputs with, instead of AOC_SESSION or token.txt")
+                .global(true),
+        )
         .subcommand(
             Command::new("bench")
                 .about("Run the benchmark")
-                .arg(arg!([day] "which day to run")),
+                .arg(arg!([day] "which day to run"))
+                .arg(arg!(-y --year <YEAR> "which year to run, defaults to the latest solved year"))
+                .arg(arg!(--timeout <SECONDS> "stop the parts that take longer and record them as timed out"))
+                .arg(arg!(--tag <TAG> "only run the days tagged with it, e.g. grid")),
         )
         .subcommand(
             Command::new("solve")
                 .about("Solve a day")
-                .arg(arg!([day] "which day to solve")),
+                .arg(arg!([day] "which day to solve"))
+                .arg(arg!(-y --year <YEAR> "which year to solve, defaults to the latest solved year"))
+                .arg(
+                    arg!(-p --param <PARAM> "set a solver parameter, e.g. width=7")
+                        .action(ArgAction::Append),
+                )
+                .arg(arg!(--variant <NAME> "which implementation to use for the parts that have it"))
+                .arg(arg!(--timeout <SECONDS> "stop the parts that take longer"))
+                .arg(arg!(--explain "show how the parts got their answers, for the solvers that explain it"))
+                .arg(
+                    arg!(--all "solve every day in parallel and show a table of the answers")
+                        .conflicts_with_all(["day", "variant", "explain"]),
+                )
+                .arg(
+                    arg!(-j --jobs <N> "how many parts to solve at once, defaults to the number of cores")
+                        .requires("all"),
+                ),
+        )
+        .subcommand(
+            Command::new("visualize")
+                .about("Play the frames recorded by a part in the terminal, or save them as a GIF")
+                .arg(arg!(<day> "which day to visualize"))
+                .arg(arg!(<part> "which part to visualize"))
+                .arg(arg!(-y --year <YEAR> "which year to visualize, defaults to the latest solved year"))
+                .arg(
+                    arg!(-p --param <PARAM> "set a solver parameter, e.g. width=7")
+                        .action(ArgAction::Append),
+                )
+                .arg(arg!(-o --output <FILE> "save the frames as an animated GIF instead of playing them"))
+                .arg(arg!(--fps <FPS> "how many frames to show per second, defaults to 30"))
+                .arg(arg!(--every <N> "only keep one frame in N, to speed up long simulations"))
+                .arg(arg!(--scale <PIXELS> "size of the cells in the GIF, defaults to 4")),
+        )
+        .subcommand(
+            Command::new("stress")
+                .about("Compare the variants of a part on random inputs, and minimize an input where they don't agree")
+                .arg(arg!(<day> "which day to stress"))
+                .arg(arg!(<part> "which part to stress"))
+                .arg(arg!(-y --year <YEAR> "which year to stress, defaults to the latest solved year"))
+                .arg(arg!(--runs <N> "how many inputs to try, defaults to 100"))
+                .arg(arg!(--size <N> "how large the inputs are, defaults to 20"))
+                .arg(arg!(--seed <SEED> "seed of the first input, defaults to a random one")),
+        )
+        .subcommand(
+            Command::new("submit")
+                .about("Solve a part and submit its answer")
+                .arg(arg!(<day> "which day to submit"))
+                .arg(arg!(<part> "which part to submit"))
+                .arg(arg!(-y --year <YEAR> "which year to submit, defaults to the latest solved year")),
+        )
+        .subcommand(
+            Command::new("check")
+                .about("Check that all the implementations of a part agree on the answer")
+                .arg(arg!([day] "which day to check, defaults to all days"))
+                .arg(arg!(-y --year <YEAR> "which year to check, defaults to the latest solved year")),
+        )
+        .subcommand(
+            Command::new("examples")
+                .about("Check the solvers against the puzzle examples")
+                .arg(arg!([day] "which day to check, defaults to all days"))
+                .arg(arg!(-y --year <YEAR> "which year to check, defaults to the latest solved year")),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Check the answers on the cached inputs against the known answers")
+                .arg(arg!([day] "which day to verify, defaults to all days"))
+                .arg(arg!(-y --year <YEAR> "which year to verify, defaults to all years"))
+                .arg(arg!(--accept "record the answers of the parts that don't have a known answer yet")),
         )
         .get_matches();
 
+    // only configured when needed, checking the examples works offline and without a token
+    let puzzle_source = LazyCell::new(|| {
+        CachedOnlinePuzzleSource::new(
+            env!("CARGO_PKG_NAME"),
+            repo_directory.as_deref(),
+            matches.get_one::<String>("token").map(String::as_str),
+        )
+        .expect("failed to configure puzzle source")
+    });
+
+    // benchmarks always run silently, their inputs don't get this context
+    let context = SolverContext::new(if matches.get_flag("verbose") {
+        Verbosity::Debug
+    } else if matches.get_flag("quiet") {
+        Verbosity::Quiet
+    } else {
+        Verbosity::Progress
+    });
+
     if let Some(bench_args) = matches.subcommand_matches("bench") {
+        let year = get_year_arg(bench_args, solvers)?;
+        let timeout = get_timeout_arg(bench_args)?;
+        let tag = bench_args.get_one::<String>("tag");
+        let is_tagged = |day: Day| {
+            tag.is_none_or(|tag| {
+                solvers
+                    .get(&(year, day))
+                    .and_then(DaySolver::metadata)
+                    .is_some_and(|metadata| metadata.has_tag(tag))
+            })
+        };
         let benchmarks = {
-            if let Some(day) = bench_args.get_one::<String>("day") {
+            if let Some(day) = get_day_arg(bench_args)? {
                 get_cached_benchmarks()
                     .map(|mut bench| {
                         bench.extend(run_benchmarks(
-                            &solvers,
-                            &puzzle_source,
-                            iter::once(day.parse::<Day>().unwrap()),
+                            solvers,
+                            &*puzzle_source,
+                            year,
+                            iter::once(day).filter(|&day| is_tagged(day)),
+                            timeout,
                         ));
                         bench
                     })
-                    .or_else(|| Some(run_benchmarks(&solvers, &puzzle_source, get_days_iter())))
+                    .or_else(|| {
+                        Some(run_benchmarks(
+                            solvers,
+                            &*puzzle_source,
+                            year,
+                            get_days_iter().filter(|&day| is_tagged(day)),
+                            timeout,
+                        ))
+                    })
                     .unwrap()
             } else {
-                run_benchmarks(&solvers, &puzzle_source, get_days_iter())
+                get_cached_benchmarks()
+                    .map(|mut bench| {
+                        // the days left out by the tag keep their previous results
+                        bench.retain(|&(cached_year, cached_day, _), _| {
+                            cached_year != year || !is_tagged(cached_day)
+                        });
+                        bench
+                    })
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(run_benchmarks(
+                        solvers,
+                        &*puzzle_source,
+                        year,
+                        get_days_iter().filter(|&day| is_tagged(day)),
+                        timeout,
+                    ))
+                    .collect()
             }
         };
         save_cached_benchmarks(&benchmarks);
-        update_readme(&benchmarks);
+        update_readme(&benchmarks, solvers);
         Ok(())
     } else if let Some(solve_args) = matches.subcommand_matches("solve") {
-        if let Some(day) = solve_args.get_one::<String>("day") {
-            solve_one(&solvers, &puzzle_source, day.parse::<Day>().unwrap())
+        let year = get_year_arg(solve_args, solvers)?;
+        let options = SolveOptions {
+            params: get_params_arg(solve_args)?,
+            variant: solve_args.get_one::<String>("variant").cloned(),
+            context,
+            timeout: get_timeout_arg(solve_args)?,
+            explain: solve_args.get_flag("explain"),
+        };
+        if solve_args.get_flag("all") {
+            let jobs = solve_args
+                .get_one::<String>("jobs")
+                .map(|jobs| {
+                    jobs.parse::<usize>()
+                        .map_err(|e| format!("invalid number of jobs {jobs}: {e}"))
+                })
+                .transpose()?;
+            solve_all(solvers, &*puzzle_source, year, &options, jobs)
+        } else if let Some(day) = get_day_arg(solve_args)? {
+            solve_one(solvers, &*puzzle_source, year, day, &options)
+        } else {
+            solve_latest(solvers, &*puzzle_source, year, &options)
+        }
+    } else if let Some(check_args) = matches.subcommand_matches("check") {
+        let year = get_year_arg(check_args, solvers)?;
+        if let Some(day) = get_day_arg(check_args)? {
+            check_variants(solvers, &*puzzle_source, year, iter::once(day), &context)
         } else {
-            solve_latest(&solvers, &puzzle_source)
+            check_variants(solvers, &*puzzle_source, year, get_days_iter(), &context)
         }
+    } else if let Some(visualize_args) = matches.subcommand_matches("visualize") {
+        let year = get_year_arg(visualize_args, solvers)?;
+        let options = VisualizeOptions {
+            params: get_params_arg(visualize_args)?,
+            output: visualize_args
+                .get_one::<String>("output")
+                .map(PathBuf::from),
+            fps: get_number_arg(visualize_args, "fps", 30)?,
+            every: get_number_arg(visualize_args, "every", 1)?,
+            scale: get_number_arg(visualize_args, "scale", 4)?,
+        };
+        visualize_part(
+            solvers,
+            &*puzzle_source,
+            year,
+            get_number_arg(visualize_args, "day", 0)?,
+            get_number_arg(visualize_args, "part", 0)?,
+            options,
+            context,
+        )
+    } else if let Some(stress_args) = matches.subcommand_matches("stress") {
+        let year = get_year_arg(stress_args, solvers)?;
+        let seed = match stress_args.get_one::<String>("seed") {
+            Some(seed) => seed
+                .parse::<u64>()
+                .map_err(|e| format!("invalid seed {seed}: {e}"))?,
+            None => fastrand::u64(..),
+        };
+        let options = StressOptions {
+            runs: get_number_arg(stress_args, "runs", 100)?,
+            size: get_number_arg(stress_args, "size", 20)?,
+            seed,
+        };
+        stress_part(
+            solvers,
+            year,
+            get_number_arg(stress_args, "day", 0)?,
+            get_number_arg(stress_args, "part", 0)?,
+            options,
+        )
+    } else if let Some(submit_args) = matches.subcommand_matches("submit") {
+        let year = get_year_arg(submit_args, solvers)?;
+        submit_part(
+            solvers,
+            &puzzle_source,
+            year,
+            get_number_arg(submit_args, "day", 0)?,
+            get_number_arg(submit_args, "part", 0)?,
+            context,
+        )
+    } else if let Some(examples_args) = matches.subcommand_matches("examples") {
+        let year = get_year_arg(examples_args, solvers)?;
+        let day = get_day_arg(examples_args)?;
+        check_examples(solvers, year, day)
+    } else if let Some(verify_args) = matches.subcommand_matches("verify") {
+        let day = get_day_arg(verify_args)?;
+        let years = if verify_args.contains_id("year") || day.is_some() {
+            vec![get_year_arg(verify_args, solvers)?]
+        } else {
+            solvers
+                .keys()
+                .map(|&(year, _)| year)
+                .unique()
+                .sorted()
+                .collect()
+        };
+        verify_answers(
+            solvers,
+            &puzzle_source,
+            &years,
+            day,
+            verify_args.get_flag("accept"),
+            &context,
+        )
+    } else {
+        solve_latest(
+            solvers,
+            &*puzzle_source,
+            get_last_year(solvers).ok_or("no solved years".to_string())?,
+            &SolveOptions {
+                context,
+                ..SolveOptions::default()
+            },
+        )
+    }
+}
+
+/// The root of the repo the binary runs in, found by walking up from the current directory to the
+/// `Cargo.toml` of the workspace, or of the package outside of a workspace.
+fn find_repo_directory() -> Option<PathBuf> {
+    let current_directory = std::env::current_dir().ok()?;
+    let manifests = current_directory
+        .ancestors()
+        .filter(|directory| directory.join("Cargo.toml").is_file())
+        .collect_vec();
+    manifests
+        .iter()
+        .find(|directory| {
+            fs::read_to_string(directory.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .or(manifests.first())
+        .map(|directory| directory.to_path_buf())
+}
+
+fn get_year_arg(args: &ArgMatches, solvers: &SolverMap) -> Result<Year, String> {
+    if let Some(year) = args.get_one::<String>("year") {
+        year.parse::<Year>()
+            .map_err(|e| format!("invalid year {year}: {e}"))
     } else {
-        solve_latest(&solvers, &puzzle_source)
+        get_last_year(solvers).ok_or("no solved years".to_string())
     }
 }
 
-fn solve_latest(solvers: &SolverMap, puzzle_source: &impl PuzzleSource) -> Result<(), String> {
+fn get_timeout_arg(args: &ArgMatches) -> Result<Option<Duration>, String> {
+    args.get_one::<String>("timeout")
+        .map(|timeout| {
+            timeout
+                .parse::<f64>()
+                .ok()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                .ok_or_else(|| format!("invalid timeout {timeout}, expected a number of seconds"))
+        })
+        .transpose()
+}
+
+/// Parses a positive number, or returns `default` if it isn't set.
+fn get_number_arg<T: FromStr + Default + PartialOrd>(
+    args: &ArgMatches,
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    let Some(value) = args.get_one::<String>(name) else {
+        return Ok(default);
+    };
+    value
+        .parse::<T>()
+        .ok()
+        .filter(|number| *number > T::default())
+        .ok_or_else(|| format!("invalid {name} {value}, expected a positive number"))
+}
+
+/// The day a subcommand is restricted to, if it's given.
+fn get_day_arg(args: &ArgMatches) -> Result<Option<Day>, String> {
+    args.contains_id("day")
+        .then(|| get_number_arg(args, "day", 0))
+        .transpose()
+}
+
+fn get_params_arg(args: &ArgMatches) -> Result<Params, String> {
+    let mut params = Params::default();
+    for param in args.get_many::<String>("param").into_iter().flatten() {
+        params.extend(param.parse::<Params>()?);
+    }
+    Ok(params)
+}
+
+fn solve_latest(
+    solvers: &'static SolverMap,
+    puzzle_source: &impl PuzzleSource,
+    year: Year,
+    options: &SolveOptions,
+) -> Result<(), String> {
     solve_one(
         solvers,
         puzzle_source,
-        get_last_day(solvers).ok_or("no solved days".to_string())?,
+        year,
+        get_last_day(solvers, year).ok_or(format!("no solved days in {year}"))?,
+        options,
     )
 }
 
-fn get_last_day(solver_map: &SolverMap) -> Option<Day> {
+fn get_last_year(solver_map: &SolverMap) -> Option<Year> {
+    solver_map.keys().map(|&(year, _)| year).max()
+}
+
+fn get_last_day(solver_map: &SolverMap, year: Year) -> Option<Day> {
     get_days_iter()
-        .flat_map(|day| {
-            solver_map
-                .get(&(day, 1))
-                .or(solver_map.get(&(day, 2)))
-                .and(Some(day))
-        })
+        .filter(|&day| solver_map.contains_key(&(year, day)))
         .last()
 }
 
 fn solve_one(
+    solver_map: &'static SolverMap,
+    puzzle_source: &impl PuzzleSource,
+    year: Year,
+    day: Day,
+    options: &SolveOptions,
+) -> Result<(), String> {
+    let solver = solver_map.get(&(year, day));
+    if let Some(variant) = &options.variant {
+        let has_variant = solver.is_some_and(|solver| {
+            (1..=2).any(|part| solver.variants(part).contains(&variant.as_str()))
+        });
+        if !has_variant {
+            return Err(format!("{year} day {day} has no variant named {variant}"));
+        }
+    }
+
+    let input = puzzle_source
+        .get_input(year, day)
+        .expect("failed to get puzzle input")
+        .with_params(options.params.clone());
+    let input = match solver {
+        Some(solver) => solver.prepare(input),
+        None => input,
+    };
+    match solver.and_then(DaySolver::metadata) {
+        Some(metadata) => println!("{year} Day {day}: {}", metadata.title),
+        None => println!("{year} Day {day}"),
+    }
+
+    // parse once and share the result between both parts
+    let parsed = solver.map(|solver| {
+        let input = input.clone();
+        options
+            .context
+            .run_with_timeout(options.timeout, move |context| {
+                solver.parse(&input.with_context(context))
+            })
+            .map(Arc::new)
+    });
+
+    let mut failed_parts = vec![];
+    for part in 1..=2 as Part {
+        // parts that don't have the requested variant use their default one
+        let variant = solver.and_then(|solver| {
+            solver
+                .variants(part)
+                .into_iter()
+                .find(|&variant| options.variant.as_deref() == Some(variant))
+        });
+        let context = if options.explain {
+            options.context.clone().explaining()
+        } else {
+            options.context.clone()
+        };
+        let result = match (solver, &parsed) {
+            (Some(solver), Some(Ok(parsed))) => {
+                let (input, parsed) = (input.clone(), parsed.clone());
+                context.run_with_timeout(options.timeout, move |context| {
+                    let input = input.with_context(context);
+                    match variant {
+                        Some(variant) => solver.solve_variant(&input, &parsed, part, variant),
+                        None => solver.solve_parsed(&input, &parsed, part),
+                    }
+                })
+            }
+            (_, Some(Err(e))) => Err(e.clone()),
+            _ => Err(SolveError::NotImplemented),
+        };
+        let label = variant.map_or_else(
+            || format!("part {part}"),
+            |variant| format!("part {part} ({variant})"),
+        );
+        match result {
+            Ok(answer @ Answer::Grid(_)) => println!("- {label}:\n{answer}"),
+            Ok(answer) => println!("- {label}: {answer}"),
+            Err(SolveError::NotImplemented) => println!("- {label}: not implemented"),
+            Err(SolveError::Parse(e)) => {
+                println!("- {label}: invalid input at {e}");
+                failed_parts.push(part);
+            }
+            Err(e @ SolveError::InvalidParam { .. }) => {
+                println!("- {label}: {e}");
+                failed_parts.push(part);
+            }
+            Err(SolveError::Runtime(e)) => {
+                println!("- {label}: failed: {e}");
+                failed_parts.push(part);
+            }
+            Err(e @ (SolveError::Cancelled | SolveError::TimedOut(_))) => {
+                println!("- {label}: {e}");
+                failed_parts.push(part);
+            }
+        }
+        for explanation in context.take_explanation() {
+            println!(
+                "{}",
+                explanation
+                    .lines()
+                    .map(|line| format!("    {line}"))
+                    .join("\n")
+            );
+        }
+    }
+
+    if failed_parts.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{year} day {day} failed on parts {failed_parts:?}"))
+    }
+}
+
+/// How [visualize_part] shows the frames.
+struct VisualizeOptions {
+    params: Params,
+    /// Saves the frames as a GIF instead of playing them in the terminal
+    output: Option<PathBuf>,
+    fps: u16,
+    /// Only one frame in `every` is shown
+    every: usize,
+    /// Size of the cells in the GIF, in pixels
+    scale: u16,
+}
+
+/// Solves a part while playing or saving the frames it records.
+fn visualize_part(
     solver_map: &SolverMap,
     puzzle_source: &impl PuzzleSource,
+    year: Year,
+    day: Day,
+    part: Part,
+    options: VisualizeOptions,
+    context: SolverContext,
+) -> Result<(), String> {
+    let solver = solver_map
+        .get(&(year, day))
+        .ok_or_else(|| format!("{year} day {day} isn't solved"))?;
+    let input = puzzle_source
+        .get_input(year, day)
+        .map_err(|e| format!("failed to get puzzle input: {e}"))?
+        .with_params(options.params);
+    let input = solver.prepare(input);
+
+    // the solver waits for the frames to be shown, instead of piling them up
+    let (sender, receiver) = mpsc::sync_channel::<Frame>(FRAME_BUFFER);
+    let recorded = AtomicUsize::new(0);
+    let every = options.every;
+    let context = context.recording(move |frame| {
+        if recorded
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(every)
+        {
+            // the player only stops early on errors, which it reports
+            let _ = sender.send(frame);
+        }
+    });
+    let (output, fps, scale) = (options.output, options.fps, options.scale);
+    let player = thread::spawn(move || match &output {
+        Some(path) => save_gif(receiver, path, scale, fps).map(|frames| (frames, output)),
+        None => play_frames(receiver, fps).map(|frames| (frames, None)),
+    });
+    // dropping the input and its context closes the channel, which ends the player
+    let result = solver.solve(&input.with_context(context), part);
+    let (frames, output) = player
+        .join()
+        .map_err(|_| "the player panicked".to_owned())??;
+
+    match result {
+        Ok(answer @ Answer::Grid(_)) => println!("{year} Day {day} part {part}:\n{answer}"),
+        Ok(answer) => println!("{year} Day {day} part {part}: {answer}"),
+        Err(e) => return Err(format!("{year} day {day} part {part} failed: {e}")),
+    }
+    if frames == 0 {
+        return Err(format!(
+            "{year} day {day} part {part} doesn't record any frames"
+        ));
+    }
+    if let Some(path) = output {
+        println!("saved {frames} frames to {}", path.display());
+    }
+    Ok(())
+}
+
+/// How many frames can wait to be shown.
+const FRAME_BUFFER: usize = 16;
+
+fn play_frames(frames: Receiver<Frame>, fps: u16) -> Result<usize, String> {
+    let delay = Duration::from_secs_f64(1.0 / fps as f64);
+    let mut stdout = io::stdout().lock();
+    let mut count = 0;
+    for frame in frames {
+        // clear the screen once, then draw each frame over the previous one
+        let clear = if count == 0 { "\x1b[2J" } else { "" };
+        write!(stdout, "{clear}\x1b[H{}", visualize::to_ansi(&frame))
+            .and_then(|_| stdout.flush())
+            .map_err(|e| format!("failed to show the frame: {e}"))?;
+        count += 1;
+        thread::sleep(delay);
+    }
+    Ok(count)
+}
+
+fn save_gif(frames: Receiver<Frame>, path: &Path, scale: u16, fps: u16) -> Result<usize, String> {
+    let file =
+        File::create(path).map_err(|e| format!("failed to create {}: {e}", path.display()))?;
+    let mut gif = GifWriter::new(BufWriter::new(file), scale, fps);
+    let mut count = 0;
+    for frame in frames {
+        gif.write(&frame).map_err(|e| e.to_string())?;
+        count += 1;
+    }
+    if gif.finish().map_err(|e| e.to_string())?.is_none() {
+        let _ = fs::remove_file(path);
+    }
+    Ok(count)
+}
+
+/// Runs every variant of a part on generated inputs, and shows the first input where they
+/// disagree or panic once minimized.
+fn stress_part(
+    solver_map: &SolverMap,
+    year: Year,
+    day: Day,
+    part: Part,
+    options: StressOptions,
+) -> Result<(), String> {
+    let solver = solver_map
+        .get(&(year, day))
+        .ok_or_else(|| format!("{year} day {day} isn't solved"))?;
+    let variants = solver.variants(part);
+    if variants.is_empty() {
+        return Err(format!("{year} day {day} part {part} isn't solved"));
+    }
+    if !solver.has_generator() {
+        return Err(format!("{year} day {day} has no input generator"));
+    }
+    println!(
+        "{year} Day {day} part {part}: {} on {} inputs of size {} from seed {}",
+        variants.join(", "),
+        options.runs,
+        options.size,
+        options.seed
+    );
+
+    // the panics are part of the report, instead of being printed for every input tried
+    let panic_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = stress(solver, part, options);
+    panic::set_hook(panic_hook);
+
+    match result {
+        None => Err(format!("{year} day {day} has no input generator")),
+        Some(Ok(())) => {
+            println!("- no differences");
+            Ok(())
+        }
+        Some(Err(failure)) => {
+            println!("- seed {} fails, minimized to:", failure.seed);
+            for line in failure.input.get_raw().lines() {
+                println!("    {line}");
+            }
+            let params = failure.input.params().to_string();
+            if !params.is_empty() {
+                println!("  with parameters {params}");
+            }
+            for (variant, outcome) in failure.outcomes {
+                println!("  - {variant}: {outcome}");
+            }
+            Err(format!(
+                "{year} day {day} part {part} fails on seed {}",
+                failure.seed
+            ))
+        }
+    }
+}
+
+/// Solves a part with its default variant and submits the answer, recording it as known once
+/// it's correct.
+fn submit_part(
+    solver_map: &SolverMap,
+    puzzle_source: &CachedOnlinePuzzleSource,
+    year: Year,
     day: Day,
+    part: Part,
+    context: SolverContext,
 ) -> Result<(), String> {
+    let solver = solver_map
+        .get(&(year, day))
+        .filter(|solver| !solver.variants(part).is_empty())
+        .ok_or_else(|| format!("{year} day {day} part {part} isn't solved"))?;
     let input = puzzle_source
-        .get_input(day)
-        .expect("failed to get puzzle input");
-    println!("Day {day}");
+        .get_input(year, day)
+        .map_err(|e| format!("failed to get puzzle input: {e}"))?;
+    let input = solver.prepare(input.with_context(context));
+    let answer = solver.solve(&input, part).map_err(|e| e.to_string())?;
+    if let Answer::Grid(_) = answer {
+        return Err(format!(
+            "{year} day {day} part {part} is a grid, read it and submit it on the website:\n{answer}"
+        ));
+    }
+
+    // wrong answers lock the part for a while, so the same one is never submitted twice
+    let history = puzzle_source.get_submission_history();
+    match history
+        .check(year, day, part, &answer)
+        .map_err(|e| e.to_string())?
+    {
+        AnswerCheck::Unknown => {}
+        check @ AnswerCheck::AlreadySubmitted(_) => {
+            return Err(format!(
+                "not submitting {answer} for {year} day {day} part {part}: {check}"
+            ));
+        }
+        check @ AnswerCheck::OutOfBounds { .. } => {
+            println!("warning: {check}, submitting {answer} anyway");
+        }
+    }
+
+    println!("{year} Day {day} part {part}: submitting {answer}");
+    let outcome = puzzle_source
+        .submit_answer(year, day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    println!("- {outcome}");
+    history
+        .record(year, day, part, answer.clone(), &outcome)
+        .map_err(|e| e.to_string())?;
+    if outcome != SubmitOutcome::Correct {
+        return Err(format!("{year} day {day} part {part} wasn't accepted"));
+    }
+
+    let store = puzzle_source.get_answer_store();
+    if store
+        .get(year, day, part)
+        .map_err(|e| e.to_string())?
+        .is_none()
+    {
+        store
+            .set(year, day, part, answer)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// The answer of a part solved by [solve_all].
+struct SolvedPart {
+    day: Day,
+    part: Part,
+    /// Only set for the days that have a parser
+    parse_duration: Option<Duration>,
+    /// Not set if the input couldn't be read or parsed
+    duration: Option<Duration>,
+    result: Result<Answer, String>,
+}
+
+/// Solves every part of `year` on `jobs` threads, and prints their answers in day order once
+/// they're all done.
+fn solve_all(
+    solver_map: &'static SolverMap,
+    puzzle_source: &impl PuzzleSource,
+    year: Year,
+    options: &SolveOptions,
+    jobs: Option<usize>,
+) -> Result<(), String> {
+    // the inputs are fetched one at a time, to go easy on the API
+    let days = get_days_iter()
+        .filter_map(|day| solver_map.get(&(year, day)).map(|solver| (day, solver)))
+        .map(|(day, solver)| {
+            let input = puzzle_source
+                .get_input(year, day)
+                .map(|input| solver.prepare(input.with_params(options.params.clone())))
+                .map_err(|e| format!("failed to get input: {e}"));
+            (day, solver, input)
+        })
+        .collect_vec();
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let start = Instant::now();
+    let mut solved: Vec<SolvedPart> = pool.install(|| {
+        days.into_par_iter()
+            .flat_map(|(day, solver, input)| solve_day_parts(solver, day, input, options))
+            .collect()
+    });
+    let elapsed = start.elapsed();
+    solved.sort_by_key(|solved| (solved.day, solved.part));
+
+    let rows = solved
+        .iter()
+        .map(|solved| {
+            let answer = match &solved.result {
+                Ok(Answer::Grid(rows)) => format!("grid of {} rows", rows.len()),
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("error: {e}"),
+            };
+            [
+                format!("{:02}", solved.day),
+                solved.part.to_string(),
+                answer,
+                solved
+                    .parse_duration
+                    .map_or_else(|| "-".to_owned(), format_duration),
+                solved
+                    .duration
+                    .map_or_else(|| "-".to_owned(), format_duration),
+            ]
+        })
+        .collect_vec();
     println!(
-        "- part 1: {}",
-        solver_map
-            .get(&(day, 1))
-            .and_then(|solver| solver(&input))
-            .unwrap_or("-".to_string()),
+        "{}",
+        format_table(&["Day", "Part", "Answer", "Parse", "Time"], &rows)
     );
     println!(
-        "- part 2: {}",
-        solver_map
-            .get(&(day, 2))
-            .and_then(|solver| solver(&input))
-            .unwrap_or("-".to_string()),
+        "{year}: {} parts in {}",
+        solved.len(),
+        format_duration(elapsed)
     );
 
-    Ok(())
+    let failed = solved
+        .iter()
+        .filter(|solved| solved.result.is_err())
+        .count();
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} parts failed"))
+    }
 }
 
-fn run_benchmarks(
+/// Parses the input of a day once, then solves its parts in parallel.
+fn solve_day_parts(
+    solver: &'static DaySolver,
+    day: Day,
+    input: Result<PuzzleInput, String>,
+    options: &SolveOptions,
+) -> Vec<SolvedPart> {
+    let parts = (1..=2)
+        .filter(|&part| !solver.variants(part).is_empty())
+        .collect_vec();
+    let start = Instant::now();
+    let parsed = input.and_then(|input| {
+        let parse_input = input.clone();
+        options
+            .context
+            .run_with_timeout(options.timeout, move |context| {
+                solver.parse(&parse_input.with_context(context))
+            })
+            .map(|parsed| (input, Arc::new(parsed)))
+            .map_err(|e| e.to_string())
+    });
+    let parse_duration = (parsed.is_ok() && solver.has_parser()).then(|| start.elapsed());
+
+    parts
+        .into_par_iter()
+        .map(|part| {
+            let (duration, result) = match &parsed {
+                Ok((input, parsed)) => {
+                    let (input, parsed) = (input.clone(), parsed.clone());
+                    let start = Instant::now();
+                    let result = options
+                        .context
+                        .run_with_timeout(options.timeout, move |context| {
+                            solver.solve_parsed(&input.with_context(context), &parsed, part)
+                        })
+                        .map_err(|e| e.to_string());
+                    (Some(start.elapsed()), result)
+                }
+                Err(e) => (None, Err(e.clone())),
+            };
+            SolvedPart {
+                day,
+                part,
+                parse_duration,
+                duration,
+                result,
+            }
+        })
+        .collect()
+}
+
+fn check_examples(solver_map: &SolverMap, year: Year, day: Option<Day>) -> Result<(), String> {
+    let days = get_example_days()
+        .filter(|&(y, d)| y == year && day.is_none_or(|day| day == d))
+        .map(|(_, d)| d)
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(match day {
+            Some(day) => format!("no examples for {year} day {day}"),
+            None => format!("no examples for {year}"),
+        });
+    }
+
+    let mut failed = 0;
+    for day in days {
+        println!("{year} Day {day}");
+        let Some(solver) = solver_map.get(&(year, day)) else {
+            println!("- not solved");
+            continue;
+        };
+        let examples = get_examples(year, day).map_err(|e| format!("{year} day {day}: {e}"))?;
+        for (i, example) in examples.iter().enumerate() {
+            let name = example.name.clone().unwrap_or_else(|| format!("{}", i + 1));
+            for part in 1..=2 as Part {
+                let variants = solver.variants(part);
+                if variants.is_empty() && example.expected(part).is_some() {
+                    println!("- example {name} part {part}: not implemented");
+                }
+                for &variant in &variants {
+                    let label = if variants.len() > 1 {
+                        format!("example {name} part {part} ({variant})")
+                    } else {
+                        format!("example {name} part {part}")
+                    };
+                    match example.check(solver, part, variant) {
+                        None => {}
+                        Some(Ok(answer)) => println!("- {label}: ok ({answer})"),
+                        Some(Err(e)) => {
+                            println!("- {label}: {e}");
+                            failed += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} examples failed"))
+    }
+}
+
+fn check_variants(
     solver_map: &SolverMap,
     puzzle_source: &impl PuzzleSource,
+    year: Year,
     days: impl Iterator<Item = Day>,
+    context: &SolverContext,
+) -> Result<(), String> {
+    let mut disagreements = 0;
+    let mut checked_days = 0;
+
+    for day in days {
+        let Some(solver) = solver_map.get(&(year, day)) else {
+            continue;
+        };
+        if (1..=2).all(|part| solver.variants(part).len() < 2) {
+            continue;
+        }
+        checked_days += 1;
+
+        let input = puzzle_source
+            .get_input(year, day)
+            .expect("failed to get puzzle input");
+        let input = solver.prepare(input.with_context(context.clone()));
+        println!("{year} Day {day}");
+        let parsed = solver
+            .parse(&input)
+            .map_err(|e| format!("{year} day {day}: {e}"))?;
+
+        for part in 1..=2 as Part {
+            let variants = solver.variants(part);
+            if variants.len() < 2 {
+                continue;
+            }
+            let answers = variants
+                .iter()
+                .map(|&variant| {
+                    (
+                        variant,
+                        solver.solve_variant(&input, &parsed, part, variant),
+                    )
+                })
+                .collect_vec();
+            if let Ok(Ok(answer)) = answers.iter().map(|(_, a)| a).all_equal_value() {
+                println!(
+                    "- part {part}: {} variants agree on {answer}",
+                    answers.len()
+                );
+            } else {
+                println!("- part {part}: variants disagree");
+                for (variant, answer) in answers {
+                    match answer {
+                        Ok(answer) => println!("  - {variant}: {answer}"),
+                        Err(e) => println!("  - {variant}: {e}"),
+                    }
+                }
+                disagreements += 1;
+            }
+        }
+    }
+
+    if checked_days == 0 {
+        println!("no parts with several variants in {year}");
+    }
+
+    if disagreements == 0 {
+        Ok(())
+    } else {
+        Err(format!("variants disagree on {disagreements} parts"))
+    }
+}
+
+fn verify_answers(
+    solver_map: &SolverMap,
+    puzzle_source: &CachedOnlinePuzzleSource,
+    years: &[Year],
+    day: Option<Day>,
+    accept: bool,
+    context: &SolverContext,
+) -> Result<(), String> {
+    let store = puzzle_source.get_answer_store();
+    let mut failed = 0;
+    let mut unknown = 0;
+
+    for &year in years {
+        let known_answers = store.get_answers(year).map_err(|e| e.to_string())?;
+        let days = get_days_iter()
+            .filter(|&d| day.is_none_or(|day| day == d))
+            .filter(|&d| solver_map.contains_key(&(year, d)));
+        for day in days {
+            let solver = &solver_map[&(year, day)];
+            println!("{year} Day {day}");
+            // only verifies what was already downloaded, verifying shouldn't hit the API
+            let Some(input) = puzzle_source.get_cached_input(year, day) else {
+                println!("- no cached input");
+                continue;
+            };
+            let input = solver.prepare(input.with_context(context.clone()));
+            let parsed = match solver.parse(&input) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    println!("- invalid input at {e}");
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for part in 1..=2 as Part {
+                let variants = solver.variants(part);
+                let known = known_answers.get(&(day, part));
+                for (i, &variant) in variants.iter().enumerate() {
+                    let label = if variants.len() > 1 {
+                        format!("part {part} ({variant})")
+                    } else {
+                        format!("part {part}")
+                    };
+                    match (solver.solve_variant(&input, &parsed, part, variant), known) {
+                        (Err(SolveError::NotImplemented), _) => {}
+                        (Err(e), _) => {
+                            println!("- {label}: {e}");
+                            failed += 1;
+                        }
+                        (Ok(answer), Some(known)) if answer == *known => {
+                            println!("- {label}: ok")
+                        }
+                        (Ok(answer), Some(known)) => {
+                            println!("- {label}: {}", format_mismatch(known, &answer));
+                            failed += 1;
+                        }
+                        // the default variant is the reference for a new answer
+                        (Ok(answer), None) if accept && i == 0 => {
+                            store
+                                .set(year, day, part, answer.clone())
+                                .map_err(|e| e.to_string())?;
+                            println!("- {label}: recorded {answer}");
+                        }
+                        (Ok(answer), None) => {
+                            println!("- {label}: {answer} (no known answer)");
+                            unknown += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if unknown > 0 {
+        println!("{unknown} answers aren't known yet, use --accept to record them");
+    }
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(format!("{failed} parts failed verification"))
+    }
+}
+
+fn run_benchmarks(
+    solver_map: &'static SolverMap,
+    puzzle_source: &impl PuzzleSource,
+    year: Year,
+    days: impl Iterator<Item = Day>,
+    timeout: Option<Duration>,
 ) -> BenchmarkMap {
     let mut all_results = HashMap::new();
 
     for day in days {
+        let Some(solver) = solver_map.get(&(year, day)) else {
+            continue;
+        };
+        if solvers_still_running(year, day) {
+            break;
+        }
         let mut part_bench: BenchmarkMap = HashMap::new();
-        let input = LazyCell::new(|| {
-            puzzle_source
-                .get_input(day)
-                .expect("failed to get puzzle input")
+        let input = puzzle_source
+            .get_input(year, day)
+            .expect("failed to get puzzle input");
+        let input = solver.prepare(input);
+
+        // parsed once up front, so that a runaway parser times out before being benchmarked
+        let parse_input = input.clone();
+        let start = Instant::now();
+        let parsed = SolverContext::silent().run_with_timeout(timeout, move |context| {
+            solver.parse(&parse_input.with_context(context))
         });
-        for part in 1..=2 as Part {
-            if let Some(solver) = solver_map.get(&(day, part)) {
-                let bench = benchmark(|| solver(&input));
-                if let Ok(result) = bench {
-                    part_bench.insert((day, part), result);
-                } else {
-                    log::debug!("day {day} part {part} not solved");
+        let first_parse_duration = start.elapsed();
+        let parsed = match parsed {
+            Ok(parsed) => Arc::new(parsed),
+            Err(SolveError::TimedOut(timeout)) => {
+                log::error!("{year} day {day} parse: timed out after {timeout:?}");
+                // both parts need the parser, so they all time out with it
+                for part in 1..=2 as Part {
+                    let variants = solver.variants(part);
+                    for variant in &variants {
+                        log::error!("{year} day {day} part {part} {variant}: timed out");
+                    }
+                    if !variants.is_empty() {
+                        all_results
+                            .insert((year, day, part), BenchmarkResults::after_timeout(timeout));
+                    }
+                }
+                continue;
+            }
+            Err(e) => {
+                log::error!("{year} day {day} parse: {e}");
+                continue;
+            }
+        };
+        let parse_duration = if solver.has_parser() {
+            match benchmark_after_first_run(first_parse_duration, || solver.parse(&input)) {
+                Ok(result) => Some(result.average_duration),
+                Err(e) => {
+                    log::error!("{year} day {day} parse: {e}");
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        // the parts measured before solvers that timed out are left running are kept
+        let mut stopped = false;
+        'parts: for part in 1..=2 as Part {
+            let variants = solver.variants(part);
+            if variants.is_empty() {
+                log::debug!("{year} day {day} part {part} not solved");
+            }
+
+            // the default variant goes in the results, the others are compared to it
+            let mut default_duration = None;
+            for variant in variants {
+                if solvers_still_running(year, day) {
+                    stopped = true;
+                    break 'parts;
+                }
+                match benchmark_variant(solver, &input, &parsed, part, variant, timeout) {
+                    Ok(result) => match default_duration {
+                        None => {
+                            default_duration = Some(result.average_duration);
+                            part_bench.insert(
+                                (year, day, part),
+                                BenchmarkResults {
+                                    parse_duration,
+                                    ..result
+                                },
+                            );
+                        }
+                        Some(default_duration) => log::info!(
+                            "{year} day {day} part {part} {variant}: {result}, {:.2}x the default",
+                            result.average_duration.as_secs_f64() / default_duration.as_secs_f64()
+                        ),
+                    },
+                    Err(BenchmarkError::NotImplemented) => {
+                        log::debug!("{year} day {day} part {part} {variant} not solved");
+                    }
+                    Err(e) => {
+                        log::error!("{year} day {day} part {part} {variant}: {e}");
+                    }
                 }
             }
         }
@@ -151,18 +1261,74 @@ fn run_benchmarks(
 
         if !part_bench.is_empty() {
             log::info!(
-                "Day {day}: \n - part 1: {}\n - part 2: {} ",
+                "{year} Day {day}: \n - part 1: {}\n - part 2: {} ",
                 part_bench
-                    .get(&(day, 1))
+                    .get(&(year, day, 1))
                     .map(|t| t.to_string())
                     .unwrap_or("-".to_string()),
                 part_bench
-                    .get(&(day, 2))
+                    .get(&(year, day, 2))
                     .map(|t| t.to_string())
                     .unwrap_or("-".to_string()),
             );
         }
+        if stopped {
+            break;
+        }
     }
 
     all_results
 }
+
+/// Benchmarks a variant, stopping its first run after `timeout` so that a runaway one is
+/// recorded as timed out instead of blocking the benchmarks.
+fn benchmark_variant(
+    solver: &'static DaySolver,
+    input: &PuzzleInput,
+    parsed: &Arc<Parsed>,
+    part: Part,
+    variant: &'static str,
+    timeout: Option<Duration>,
+) -> Result<BenchmarkResults, BenchmarkError> {
+    let (run_input, run_parsed) = (input.clone(), parsed.clone());
+    if let Some(external) = solver.external(variant) {
+        let first_run = SolverContext::silent().run_with_timeout(timeout, move |context| {
+            external.run(&run_input.with_context(context), part)
+        });
+        match first_run {
+            Err(SolveError::TimedOut(timeout)) => Ok(BenchmarkResults::after_timeout(timeout)),
+            first_run => {
+                benchmark_process_after_first_run(first_run?, || external.run(input, part))
+            }
+        }
+    } else {
+        let start = Instant::now();
+        let first_run = SolverContext::silent().run_with_timeout(timeout, move |context| {
+            let input = run_input.with_context(context);
+            solver.solve_variant(&input, &run_parsed, part, variant)
+        });
+        let first_run_duration = start.elapsed();
+        match first_run {
+            Err(SolveError::TimedOut(timeout)) => Ok(BenchmarkResults::after_timeout(timeout)),
+            first_run => {
+                first_run?;
+                benchmark_after_first_run(first_run_duration, || {
+                    solver.solve_variant(input, parsed, part, variant)
+                })
+            }
+        }
+    }
+}
+
+/// Whether solvers that timed out are still running after their grace period, in which case
+/// they would skew the time and memory measured from `day` on.
+fn solvers_still_running(year: Year, day: Day) -> bool {
+    let running = wait_for_abandoned_solvers(CANCELLED_SOLVER_GRACE);
+    if running > 0 {
+        log::error!(
+            "{running} solvers that timed out are still running, stopping the benchmarks at \
+             {year} day {day} as they would skew the results"
+        );
+    }
+    running > 0
+}
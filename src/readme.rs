@@ -1,6 +1,6 @@
-use crate::aoc::get_days_iter;
-use crate::bench::{format_duration, format_memory, BenchmarkResults};
 use crate::BenchmarkMap;
+use aoc_framework::aoc::{get_days_iter, DaySolver, SolverMap, Year};
+use aoc_framework::bench::{format_duration, format_memory, BenchmarkResults};
 use itertools::Itertools;
 use std::env::current_dir;
 use std::fs::read_to_string;
@@ -11,7 +11,9 @@ const MARKER_START: &str = "<!---BENCH_START--->";
 const MARKER_END: &str = "<!---BENCH_END--->";
 
 fn format_day_bench(result: Option<&BenchmarkResults>) -> String {
-    if let Some(result) = result {
+    if let Some(result) = result.filter(|result| result.timed_out) {
+        format!("> {}", format_duration(result.average_duration))
+    } else if let Some(result) = result {
         format!(
             "{} / {}",
             format_duration(result.average_duration),
@@ -35,7 +37,7 @@ fn format_row<const N: usize>(row: &[impl AsRef<str>; N], widths: &[usize; N]) -
     format!("| {inner} |")
 }
 
-fn format_table<const N: usize>(
+pub fn format_table<const N: usize>(
     headers: &[impl AsRef<str>; N],
     lines: &[[impl AsRef<str>; N]],
 ) -> String {
@@ -59,44 +61,68 @@ fn format_table<const N: usize>(
         .join("\n")
 }
 
-pub fn update_readme(results: &BenchmarkMap) {
+pub fn update_readme(results: &BenchmarkMap, solvers: &SolverMap) {
     let readme_path = current_dir().unwrap().join("README.md");
     let contents = read_to_string(&readme_path).unwrap();
     let (start, middle) = contents.split_once(MARKER_START).unwrap();
     let (_, footer) = middle.split_once(MARKER_END).unwrap();
 
+    let years = results
+        .keys()
+        .map(|&(year, _, _)| year)
+        .unique()
+        .sorted()
+        .map(|year| format_year(results, solvers, year))
+        .join("\n\n");
+
+    let s = sysinfo::System::new_with_specifics(
+        RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    );
+
+    let cpu = s.cpus().first().unwrap();
+
+    let processor_name = cpu.brand().trim().to_string();
+    let sys_info = format!("Benchmark CPU: **{processor_name}**");
+    let bench = format!("{sys_info}\n\n{years}");
+
+    let updated_content = format!("{start}{MARKER_START}\n\n{bench}\n\n{MARKER_END}{footer}");
+
+    std::fs::write(&readme_path, &updated_content).unwrap();
+}
+
+fn format_year(results: &BenchmarkMap, solvers: &SolverMap, year: Year) -> String {
     let days = get_days_iter()
         .map(|d| {
-            let r1 = results.get(&(d, 1));
-            let r2 = results.get(&(d, 2));
+            let r1 = results.get(&(year, d, 1));
+            let r2 = results.get(&(year, d, 2));
             [
                 r1.or(r2).map_or_else(
                     || format!("{d:02}"),
-                    |_| format!("[{d:02}](src/solutions/day{d:02}.rs)"),
+                    |_| format!("[{d:02}](src/solutions/y{year}/day{d:02}.rs)"),
                 ),
+                solvers
+                    .get(&(year, d))
+                    .and_then(DaySolver::metadata)
+                    .map_or("", |metadata| metadata.title)
+                    .to_owned(),
+                r1.or(r2)
+                    .and_then(|r| r.parse_duration)
+                    .map_or_else(|| "-".to_owned(), format_duration),
                 format_day_bench(r1),
                 format_day_bench(r2),
             ]
         })
         .collect_vec();
 
-    let table_entries = format_table(&["Day", "Part 1", "Part 2"], &days);
-
-    let star_road = get_star_road(results.len(), get_days_iter().count() * 2);
-
-    let s = sysinfo::System::new_with_specifics(
-        RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
-    );
-
-    let cpu = s.cpus().first().unwrap();
-
-    let processor_name = cpu.brand().trim().to_string();
-    let sys_info = format!("Benchmark CPU: **{processor_name}**");
-    let bench = format!("{sys_info}\n\n{star_road}\n\n{table_entries}");
+    let table_entries = format_table(&["Day", "Puzzle", "Parse", "Part 1", "Part 2"], &days);
 
-    let updated_content = format!("{start}{MARKER_START}\n\n{bench}\n\n{MARKER_END}{footer}");
+    let current_stars = results
+        .iter()
+        .filter(|&(&(y, _, _), result)| y == year && !result.timed_out)
+        .count();
+    let star_road = get_star_road(current_stars, get_days_iter().count() * 2);
 
-    std::fs::write(&readme_path, &updated_content).unwrap();
+    format!("## {year}\n\n{star_road}\n\n{table_entries}")
 }
 
 fn get_star_road(current_stars: usize, total_stars: usize) -> String {
@@ -0,0 +1,44 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+use std::iter;
+
+#[aoc(day = 1)]
+const METADATA: Metadata = Metadata {
+    title: "Historian Hysteria",
+    tags: &["sorting", "counting"],
+    complexity: Some("O(n log n)"),
+    ..Metadata::EMPTY
+};
+
+type Columns = (Vec<i32>, Vec<i32>);
+
+#[aoc(day = 1, parser)]
+fn read_columns(input: impl Lines) -> Result<Columns, ParseError> {
+    input
+        .get_lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            input
+                .ints_in::<i32>(l)?
+                .into_iter()
+                .collect_tuple()
+                .ok_or_else(|| input.parse_error(l, "expected two columns"))
+        })
+        .process_results(|columns| columns.unzip())
+}
+
+#[aoc(day = 1, part = 1)]
+fn solve_part_1((a, b): &Columns) -> i32 {
+    a.iter()
+        .sorted()
+        .zip_eq(b.iter().sorted())
+        .map(|(a, b)| (a - b).abs())
+        .sum()
+}
+
+#[aoc(day = 1, part = 2)]
+fn solve_part_2((a, b): &Columns) -> i32 {
+    let counts = b.iter().zip(iter::repeat(1)).into_grouping_map().sum();
+    a.iter().map(|v| v * counts.get(v).unwrap_or(&0)).sum()
+}
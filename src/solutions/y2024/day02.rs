@@ -0,0 +1,50 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+
+#[aoc(day = 2)]
+const METADATA: Metadata = Metadata {
+    title: "Red-Nosed Reports",
+    tags: &["brute force"],
+    complexity: Some("O(n·m²), removing every level of every report"),
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 2, part = 1)]
+fn solve_part_1(levels: &[Vec<i64>]) -> usize {
+    levels
+        .iter()
+        .map(|l| diff(l))
+        .filter(|d| is_safe(d))
+        .count()
+}
+
+#[aoc(day = 2, part = 2)]
+fn solve_part_2(levels: &[Vec<i64>]) -> usize {
+    levels
+        .iter()
+        .filter(|l| is_safe(&diff(l)) || (0..l.len()).any(|i| is_safe(&diff(&exclude(l, i)))))
+        .count()
+}
+
+fn is_safe(diffs: &[i64]) -> bool {
+    diffs.iter().all(|&v| (1..=3).contains(&v)) || diffs.iter().all(|&v| (-3..=-1).contains(&v))
+}
+
+fn diff(values: &[i64]) -> Vec<i64> {
+    values.iter().tuple_windows().map(|(a, b)| b - a).collect()
+}
+
+#[aoc(day = 2, parser)]
+fn get_levels(input: impl Lines) -> Result<Vec<Vec<i64>>, ParseError> {
+    input.ints()
+}
+
+fn exclude(values: &[i64], index: usize) -> Vec<i64> {
+    values
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != index)
+        .map(|(_, e)| *e)
+        .collect()
+}
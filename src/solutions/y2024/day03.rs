@@ -0,0 +1,69 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use lazy_static::lazy_static;
+use regex::bytes::Regex;
+
+#[aoc(day = 3)]
+const METADATA: Metadata = Metadata {
+    title: "Mull It Over",
+    tags: &["regex", "bytes"],
+    ..Metadata::EMPTY
+};
+
+// only ASCII digits, `\d` would also match the other digits of Unicode
+lazy_static! {
+    static ref MUL_REGEX: Regex = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
+    static ref INSTRUCTION_REGEX: Regex =
+        Regex::new(r"(mul)\(([0-9]+),([0-9]+)\)|(do)\(\)|(don't)\(\)").unwrap();
+}
+
+#[aoc(day = 3, part = 1)]
+fn solve_part_1(input: &[u8]) -> i64 {
+    let caps = MUL_REGEX.captures_iter(input);
+    caps.map(|cap| {
+        let a = parse_number(cap.get(1).unwrap().as_bytes());
+        let b = parse_number(cap.get(2).unwrap().as_bytes());
+        a * b
+    })
+    .sum()
+}
+
+#[aoc(day = 3, part = 2)]
+fn solve_part_2(input: &[u8]) -> i64 {
+    let caps = INSTRUCTION_REGEX.captures_iter(input);
+    let (_, sum) = caps.fold((true, 0), |(enabled, sum), cap| {
+        let mut instruction = cap.iter().skip(1).flatten();
+
+        match instruction.next().unwrap().as_bytes() {
+            b"do" => (true, sum),
+            b"don't" => (false, sum),
+            b"mul" if enabled => {
+                let a = parse_number(instruction.next().unwrap().as_bytes());
+                let b = parse_number(instruction.next().unwrap().as_bytes());
+
+                (enabled, sum + a * b)
+            }
+            _ => (enabled, sum),
+        }
+    });
+    sum
+}
+
+/// Parses the digits matched by `\d+`, which are always valid.
+fn parse_number(digits: &[u8]) -> i64 {
+    digits
+        .iter()
+        .fold(0, |n, &digit| n * 10 + (digit - b'0') as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_ascii_digits() {
+        let input = "mul(\u{663},2)mul(2,3)do()mul(1,\u{663})".as_bytes();
+        assert_eq!(solve_part_1(input), 6);
+        assert_eq!(solve_part_2(input), 6);
+    }
+}
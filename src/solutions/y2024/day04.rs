@@ -1,12 +1,16 @@
-use crate::aoc::*;
-use crate::solution;
+use super::YEAR;
+use aoc_framework::aoc::*;
 use itertools::Itertools;
 use std::collections::HashMap;
 
-const DAY: Day = 4;
-
-solution!(DAY, solve_part_1, solve_part_2);
+#[aoc(day = 4)]
+const METADATA: Metadata = Metadata {
+    title: "Ceres Search",
+    tags: &["grid", "string search"],
+    ..Metadata::EMPTY
+};
 
+#[aoc(day = 4, part = 1)]
 fn solve_part_1(input: impl Lines) -> usize {
     let lines = get_lines(&input);
     lines
@@ -18,6 +22,7 @@ fn solve_part_1(input: impl Lines) -> usize {
         .sum::<usize>()
 }
 
+#[aoc(day = 4, part = 2)]
 fn solve_part_2(input: impl Lines) -> usize {
     let grid = Grid::from_lines(&get_lines(&input));
     let masks = [
@@ -201,34 +206,10 @@ impl Grid {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::aoc_test;
     use itertools::Itertools;
 
-    const TEST_INPUT: &str = "
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX
-";
-
     const TEST_INPUT_DIAGONALS: &str = "123\n456\n789";
 
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 18, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 9, TEST_INPUT);
-    }
-
     #[test]
     fn test_diagonals() {
         let mut diags =
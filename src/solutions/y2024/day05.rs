@@ -0,0 +1,82 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+#[aoc(day = 5)]
+const METADATA: Metadata = Metadata {
+    title: "Print Queue",
+    tags: &["sorting"],
+    complexity: Some("O(m log m) per update"),
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 5, part = 1)]
+fn solve_part_1((priority, updates): &(Rules, Updates)) -> i64 {
+    updates
+        .iter()
+        .filter_map(|v| {
+            let sorted = sort(v, priority);
+            if *v == sorted {
+                Some(sorted)
+            } else {
+                None
+            }
+        })
+        .map(|v| v[v.len() / 2] as i64)
+        .sum()
+}
+
+#[aoc(day = 5, part = 2)]
+fn solve_part_2((priority, updates): &(Rules, Updates)) -> i64 {
+    updates
+        .iter()
+        .filter_map(|v| {
+            let sorted = sort(v, priority);
+            if *v != sorted {
+                Some(sorted)
+            } else {
+                None
+            }
+        })
+        .map(|v| v[v.len() / 2] as i64)
+        .sum()
+}
+
+fn sort(line: &[u8], priority: &HashSet<(u8, u8)>) -> Vec<u8> {
+    let mut line = line.to_vec();
+    line.sort_by(|a, b| {
+        if priority.contains(&(*a, *b)) {
+            Ordering::Less
+        } else if priority.contains(&(*b, *a)) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+    line
+}
+
+type Rules = HashSet<(u8, u8)>;
+type Updates = Vec<Vec<u8>>;
+
+#[aoc(day = 5, parser)]
+fn parse(input: impl Lines) -> Result<(Rules, Updates), ParseError> {
+    let (rules, updates) = input
+        .sections()
+        .collect_tuple()
+        .ok_or_else(|| input.parse_error("", "expected the rules and the updates"))?;
+    let priority = rules
+        .get_lines()
+        .map(|line| {
+            rules
+                .ints_in::<u8>(line)?
+                .into_iter()
+                .collect_tuple()
+                .ok_or_else(|| rules.parse_error(line, "expected a rule like 47|53"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((priority, updates.ints()?))
+}
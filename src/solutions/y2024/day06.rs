@@ -1,13 +1,18 @@
-use crate::aoc::*;
-use crate::solution;
-use crate::utils::grid::{DenseGrid, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use aoc_framework::visualize::{self, Color, Frame};
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::fmt::{Formatter, Write};
+use std::fmt::{Display, Formatter, Write};
 
-const DAY: Day = 6;
-
-solution!(DAY, solve_part_1, solve_part_2);
+#[aoc(day = 6)]
+const METADATA: Metadata = Metadata {
+    title: "Guard Gallivant",
+    tags: &["grid", "simulation", "cycle detection"],
+    complexity: Some("O(cells²), an obstacle on every visited cell"),
+    ..Metadata::EMPTY
+};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum Cell {
@@ -17,24 +22,41 @@ enum Cell {
     Visited,
 }
 
-fn solve_part_1(input: impl Lines) -> i64 {
-    let mut grid = parse(input);
-    step_until_outside(&mut grid);
+#[aoc(day = 6, part = 1)]
+fn solve_part_1((grid, guard): &(DenseGrid<Cell>, XY), context: &SolverContext) -> i64 {
+    let mut grid = grid.clone();
+    step_until_outside(&mut grid, *guard, context);
     grid.find(&Cell::Visited).count() as i64
 }
 
-fn step_until_outside(grid: &mut DenseGrid<Cell>) {
-    let mut pos = grid.find(&Cell::Guard { facing: DIR_UP }).next().unwrap();
+fn step_until_outside(grid: &mut DenseGrid<Cell>, mut pos: XY, context: &SolverContext) {
+    context.frame(|| render(grid));
     while let Some(new_pos) = step_guard(grid, pos) {
         pos = new_pos;
+        context.frame(|| render(grid));
     }
 }
 
-fn solve_part_2(input: impl Lines) -> i64 {
-    let grid = parse(input);
+fn render(grid: &DenseGrid<Cell>) -> Frame {
+    visualize::frame(grid, |cell| {
+        let color = match cell {
+            Cell::Empty => Color::Default,
+            Cell::Obstacle => Color::Gray,
+            Cell::Guard { .. } => Color::Yellow,
+            Cell::Visited => Color::Blue,
+        };
+        (cell.symbol(), color)
+    })
+}
+
+#[aoc(day = 6, part = 2)]
+fn solve_part_2(
+    (grid, guard): &(DenseGrid<Cell>, XY),
+    context: &SolverContext,
+) -> Result<i64, SolveError> {
     let visited_tiles: HashSet<XY> = {
         let mut grid = grid.clone();
-        step_until_outside(&mut grid);
+        step_until_outside(&mut grid, *guard, context);
         grid.find(&Cell::Visited).collect()
     };
     (0..grid.width())
@@ -49,20 +71,30 @@ fn solve_part_2(input: impl Lines) -> i64 {
                 None
             }
         })
-        .filter_map(|mut grid| {
-            let mut pos = grid.find(&Cell::Guard { facing: DIR_UP }).next().unwrap();
-            let mut total_steps = 0;
-            while let Some(new_pos) = step_guard(&mut grid, pos) {
-                pos = new_pos;
-                total_steps += 1;
-                // this magic number seems to do the trick, but it's a terrible solution
-                if total_steps > 10000 {
-                    return Some(());
-                }
-            }
-            None
+        .map(|grid| {
+            context.check_cancelled()?;
+            Ok(gets_stuck(grid, *guard))
         })
-        .count() as i64
+        .filter_ok(|&stuck| stuck)
+        .process_results(|stuck| stuck.count() as i64)
+}
+
+/// Whether the guard walks in a loop instead of leaving the grid, which it does as soon as it
+/// turns at the same place in the same direction twice.
+fn gets_stuck(mut grid: DenseGrid<Cell>, mut pos: XY) -> bool {
+    let mut turns = HashSet::new();
+    while let Some(new_pos) = step_guard(&mut grid, pos) {
+        if new_pos == pos {
+            let Some(&Cell::Guard { facing }) = grid.at(pos.as_tuple()) else {
+                unreachable!("the guard turned without staying in place");
+            };
+            if !turns.insert((pos, facing)) {
+                return true;
+            }
+        }
+        pos = new_pos;
+    }
+    false
 }
 
 fn step_guard(grid: &mut DenseGrid<Cell>, current_guard_pos: XY) -> Option<XY> {
@@ -91,13 +123,9 @@ fn step_guard(grid: &mut DenseGrid<Cell>, current_guard_pos: XY) -> Option<XY> {
     }
 }
 
-fn parse(input: impl Lines) -> DenseGrid<Cell> {
-    DenseGrid::from_rows(
-        input
-            .get_lines()
-            .map(|l| l.chars().flat_map(Cell::try_from).collect())
-            .collect(),
-    )
+#[aoc(day = 6, parser)]
+fn parse(input: impl Lines) -> Result<(DenseGrid<Cell>, XY), ParseError> {
+    Ok((input.grid()?, input.grid_position('^')?))
 }
 
 impl TryFrom<char> for Cell {
@@ -112,9 +140,9 @@ impl TryFrom<char> for Cell {
     }
 }
 
-impl Display for Cell {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_char(match self {
+impl Cell {
+    fn symbol(&self) -> char {
+        match self {
             Cell::Empty => '.',
             Cell::Obstacle => '#',
             Cell::Visited => 'X',
@@ -123,33 +151,12 @@ impl Display for Cell {
             Cell::Guard { facing } if facing == &DIR_DOWN => 'v',
             Cell::Guard { facing } if facing == &DIR_LEFT => '<',
             _ => '?',
-        })
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 41, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 6, TEST_INPUT);
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.symbol())
     }
 }
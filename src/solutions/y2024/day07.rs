@@ -0,0 +1,105 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+#[aoc(day = 7)]
+const METADATA: Metadata = Metadata {
+    title: "Bridge Repair",
+    tags: &["brute force", "combinatorics"],
+    complexity: Some("O(3ⁿ) operator combinations per equation"),
+    ..Metadata::EMPTY
+};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Operation {
+    Sum,
+    Multiply,
+    Combine,
+}
+
+type Equation = (i64, Vec<i64>);
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Operation::Sum => "+",
+            Operation::Multiply => "*",
+            Operation::Combine => "||",
+        })
+    }
+}
+
+#[aoc(day = 7, part = 1)]
+fn solve_part_1(equations: &[Equation], context: &SolverContext) -> i64 {
+    equations
+        .iter()
+        .filter_map(check_line(&[Operation::Sum, Operation::Multiply], context))
+        .sum()
+}
+
+#[aoc(day = 7, part = 2)]
+fn solve_part_2(equations: &[Equation], context: &SolverContext) -> i64 {
+    equations
+        .iter()
+        .filter_map(check_line(
+            &[Operation::Sum, Operation::Multiply, Operation::Combine],
+            context,
+        ))
+        .sum()
+}
+
+fn check_line<'a>(
+    options: &'a [Operation],
+    context: &'a SolverContext,
+) -> impl Fn(&Equation) -> Option<i64> + use<'a> {
+    |(result, inputs)| {
+        let operations = find_operators(*result, inputs, options).next()?;
+        if context.is_explaining() {
+            let terms = inputs[1..]
+                .iter()
+                .zip(operations)
+                .map(|(n, op)| format!(" {op} {n}"))
+                .join("");
+            context.explain(format_args!("{result} = {}{terms}", inputs[0]));
+        }
+        Some(*result)
+    }
+}
+
+fn find_operators<'a, 'b>(
+    result: i64,
+    inputs: &'a [i64],
+    options: &'b [Operation],
+) -> impl Iterator<Item = Vec<Operation>> + use<'a, 'b> {
+    (0..inputs.len() - 1)
+        .map(|_| options.iter())
+        .multi_cartesian_product()
+        .filter_map(move |operations| {
+            let output = inputs.iter().skip(1).zip(operations.iter()).fold(
+                inputs[0],
+                |acc, (n, op)| match op {
+                    Operation::Sum => acc + n,
+                    Operation::Multiply => acc * n,
+                    Operation::Combine => acc * (10i64.pow(n.ilog10() + 1)) + n,
+                },
+            );
+            if output == result {
+                Some(operations.into_iter().cloned().collect())
+            } else {
+                None
+            }
+        })
+}
+
+#[aoc(day = 7, parser)]
+fn parse(input: impl Lines) -> Result<Vec<Equation>, ParseError> {
+    input
+        .get_lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| match input.ints_in(line)?.split_first() {
+            Some((&result, inputs)) if !inputs.is_empty() => Ok((result, inputs.to_vec())),
+            _ => Err(input.parse_error(line, "expected an equation like 190: 10 19")),
+        })
+        .collect()
+}
@@ -1,20 +1,25 @@
-use crate::aoc::*;
-use crate::solution;
-use crate::utils::grid::XY;
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::XY;
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 use std::collections::HashMap;
 
-const DAY: Day = 8;
-
-solution!(DAY, solve_part_1, solve_part_2);
+#[aoc(day = 8)]
+const METADATA: Metadata = Metadata {
+    title: "Resonant Collinearity",
+    tags: &["grid", "geometry"],
+    ..Metadata::EMPTY
+};
 
+#[aoc(day = 8, part = 1)]
 fn solve_part_1(input: impl Lines) -> i64 {
     solve(&input, 1, 1)
 }
 
+#[aoc(day = 8, part = 2)]
 fn solve_part_2(input: impl Lines) -> i64 {
-    // this is inefficient, but I don't like the idea of needing a max resonance, 
+    // this is inefficient, but I don't like the idea of needing a max resonance,
     // so we'll keep trying until the result doesn't change
     (50..)
         .fold_while(0, |acc, i| {
@@ -33,8 +38,8 @@ fn solve(input: &impl Lines, min_resonance: usize, max_resonance: usize) -> i64
     let h = input.get_lines().count() as i64;
     let antennas = parse(input);
     antennas
-        .iter()
-        .flat_map(|(_, points)| {
+        .values()
+        .flat_map(|points| {
             points.iter().tuple_combinations().flat_map(|(a, b)| {
                 let dl = *b - *a;
                 (min_resonance..=max_resonance)
@@ -65,32 +70,3 @@ fn parse(input: &impl Lines) -> HashMap<char, Vec<XY>> {
         .into_grouping_map()
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 14, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 34, TEST_INPUT);
-    }
-}
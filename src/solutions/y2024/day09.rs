@@ -0,0 +1,198 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+use std::collections::{LinkedList, VecDeque};
+use std::iter;
+
+#[aoc(day = 9)]
+const METADATA: Metadata = Metadata {
+    title: "Disk Fragmenter",
+    tags: &["bytes", "simulation"],
+    complexity: Some("O(n²) to move whole files"),
+    ..Metadata::EMPTY
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct File {
+    id: usize,
+    length: usize,
+}
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Block {
+    Empty(usize),
+    File(File),
+}
+
+// the disk map is a single line of digits, read as is instead of going through the lines
+const NORMALIZATION: Normalization =
+    Normalization::RAW.with_trailing_newline(TrailingNewline::Strip);
+
+#[aoc(day = 9, part = 1, normalize = NORMALIZATION)]
+fn solve_part_1(input: impl Lines) -> Result<usize, ParseError> {
+    let data = parse(&input)?;
+    let mut files_only: VecDeque<usize> = data.clone().into_iter().flatten().collect();
+    let result = data.into_iter().map(|x| match x {
+        None => files_only.pop_back(),
+        Some(_) => files_only.pop_front(),
+    });
+    Ok(checksum(result))
+}
+
+/// Moves the blocks one by one in the expanded disk map, to check the queue-based version.
+#[aoc(day = 9, part = 1, normalize = NORMALIZATION)]
+fn solve_part_1_in_place(input: impl Lines) -> Result<usize, ParseError> {
+    let mut blocks = parse(&input)?;
+    let (mut free, mut last) = (0, blocks.len());
+    loop {
+        while free < blocks.len() && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > 0 && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if free + 1 >= last {
+            break;
+        }
+        blocks.swap(free, last - 1);
+    }
+    Ok(checksum(blocks.into_iter()))
+}
+
+#[aoc(day = 9, part = 2, normalize = NORMALIZATION)]
+fn solve_part_2(input: impl Lines) -> Result<usize, ParseError> {
+    let mut blocks = parse_2(&input)?;
+    let mut files_only: Vec<_> = blocks
+        .clone()
+        .into_iter()
+        .flat_map(|block| match block {
+            Block::Empty(_) => None,
+            Block::File(file) => Some(file),
+        })
+        .rev()
+        .collect();
+    for file in &mut files_only {
+        let position = blocks
+            .iter()
+            .find_position(|x| **x == Block::File(*file))
+            .unwrap()
+            .0;
+        let mut tail = blocks.split_off(position);
+        tail.pop_front();
+        blocks.extend(iter::once(Block::Empty(file.length)));
+        blocks.append(&mut tail);
+        let insert_pos = blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, block)| match block {
+                Block::Empty(length) if *length >= file.length => Some(i),
+                _ => None,
+            })
+            .next()
+            .unwrap();
+        let mut tail = blocks.split_off(insert_pos);
+        let Block::Empty(empty_block_length) = tail.pop_front().unwrap() else {
+            panic!("Block isn't empty?")
+        };
+        blocks.extend(iter::once(Block::File(*file)));
+        if empty_block_length > file.length {
+            blocks.extend(iter::once(Block::Empty(empty_block_length - file.length)));
+        }
+        blocks.append(&mut tail);
+    }
+    Ok(checksum(blocks.iter().flat_map(|block| match block {
+        Block::Empty(length) => iter::repeat_n(None, *length),
+        Block::File(file) => iter::repeat_n(Some(file.id), file.length),
+    })))
+}
+
+/// Moves the files in the expanded disk map, scanning it for free space each time.
+#[aoc(day = 9, part = 2, normalize = NORMALIZATION)]
+fn solve_part_2_blocks(input: impl Lines) -> Result<usize, ParseError> {
+    let mut blocks = parse(&input)?;
+    let files = blocks.iter().flatten().max().map_or(0, |&id| id + 1);
+    for id in (0..files).rev() {
+        let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let length = blocks[start..]
+            .iter()
+            .take_while(|&&block| block == Some(id))
+            .count();
+        let free = blocks[..start]
+            .windows(length)
+            .position(|window| window.iter().all(Option::is_none));
+        if let Some(free) = free {
+            blocks[free..free + length].fill(Some(id));
+            blocks[start..start + length].fill(None);
+        }
+    }
+    Ok(checksum(blocks.into_iter()))
+}
+
+fn checksum(values: impl Iterator<Item = Option<usize>>) -> usize {
+    values
+        .enumerate()
+        .filter_map(|(i, value)| value.map(|v| v * i))
+        .sum()
+}
+
+/// Files of 1 to 9 blocks, with up to 9 free blocks between them.
+#[aoc(day = 9, generator)]
+fn generate(random: &mut Rng, size: usize) -> String {
+    let mut disk_map = String::new();
+    for file in 0..size.max(1) {
+        if file > 0 {
+            disk_map.push(char::from(b'0' + random.u8(0..10)));
+        }
+        disk_map.push(char::from(b'0' + random.u8(1..10)));
+    }
+    disk_map + "\n"
+}
+
+fn parse(input: &impl Lines) -> Result<Vec<Option<usize>>, ParseError> {
+    Ok(parse_lengths(input)?
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, length)| {
+            if i % 2 == 0 {
+                iter::repeat_n(Some(i / 2), length)
+            } else {
+                iter::repeat_n(None, length)
+            }
+        })
+        .collect())
+}
+
+fn parse_2(input: &impl Lines) -> Result<LinkedList<Block>, ParseError> {
+    Ok(parse_lengths(input)?
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, length)| {
+            if length > 0 {
+                if i % 2 == 0 {
+                    Some(Block::File(File { id: i / 2, length }))
+                } else {
+                    Some(Block::Empty(length))
+                }
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+fn parse_lengths(input: &impl Lines) -> Result<Vec<usize>, ParseError> {
+    let disk_map = input.get_bytes();
+    disk_map
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| {
+            if b.is_ascii_digit() {
+                Ok((b - b'0') as usize)
+            } else {
+                let c = b as char;
+                Err(input.byte_error(&disk_map[i..], format!("invalid length {c:?}")))
+            }
+        })
+        .collect()
+}
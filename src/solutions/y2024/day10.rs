@@ -1,12 +1,16 @@
-use crate::aoc::*;
-use crate::solution;
-use crate::utils::grid::{DenseGrid, XY};
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, XY};
 use rayon::prelude::*;
 use std::collections::{HashSet, VecDeque};
 
-const DAY: Day = 10;
-
-solution!(DAY, solve_part_1, solve_part_2);
+#[aoc(day = 10)]
+const METADATA: Metadata = Metadata {
+    title: "Hoof It",
+    tags: &["grid", "bfs"],
+    uses_rayon: true,
+    ..Metadata::EMPTY
+};
 
 #[derive(Eq, PartialEq, Copy, Clone)]
 enum ScoringMode {
@@ -14,14 +18,14 @@ enum ScoringMode {
     AllPaths,
 }
 
-fn solve_part_1(input: impl Lines) -> usize {
-    let map = parse(&input);
-    solve(&map, ScoringMode::PeakCount)
+#[aoc(day = 10, part = 1)]
+fn solve_part_1(map: &DenseGrid<u8>) -> usize {
+    solve(map, ScoringMode::PeakCount)
 }
 
-fn solve_part_2(input: impl Lines) -> usize {
-    let map = parse(&input);
-    solve(&map, ScoringMode::AllPaths)
+#[aoc(day = 10, part = 2)]
+fn solve_part_2(map: &DenseGrid<u8>) -> usize {
+    solve(map, ScoringMode::AllPaths)
 }
 
 fn solve(map: &DenseGrid<u8>, scoring_mode: ScoringMode) -> usize {
@@ -59,36 +63,20 @@ fn get_trail_scores(start: XY, map: &DenseGrid<u8>, scoring_mode: ScoringMode) -
     score
 }
 
-fn parse(input: &impl Lines) -> DenseGrid<u8> {
-    DenseGrid::from_rows(
+#[aoc(day = 10, parser)]
+fn parse(input: &impl Lines) -> Result<DenseGrid<u8>, ParseError> {
+    Ok(DenseGrid::from_rows(
         input
             .get_lines()
-            .map(|row| row.chars().map(|c| c.to_digit(10).unwrap() as u8).collect())
-            .collect(),
-    )
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 36, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 81, TEST_INPUT);
-    }
+            .map(|row| {
+                row.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10)
+                            .map(|height| height as u8)
+                            .ok_or_else(|| input.parse_error(&row[i..], "invalid height"))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?,
+    ))
 }
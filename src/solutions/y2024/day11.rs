@@ -1,13 +1,18 @@
-use crate::aoc::*;
-use crate::solution;
+use super::YEAR;
+use aoc_framework::aoc::*;
 use std::collections::HashMap;
 use std::iter::Flatten;
 use std::str::FromStr;
 
-const DAY: Day = 11;
-
-solution!(DAY, solve_part_1, solve_part_2);
+#[aoc(day = 11)]
+const METADATA: Metadata = Metadata {
+    title: "Plutonian Pebbles",
+    tags: &["dp", "memoization"],
+    complexity: Some("O(distinct stones × blinks)"),
+    ..Metadata::EMPTY
+};
 
+#[aoc(day = 11, part = 1)]
 fn solve_part_1(input: impl Lines) -> usize {
     let stones = parse(input);
     let mut corridor = CachedStoneCorridor::default();
@@ -15,6 +20,18 @@ fn solve_part_1(input: impl Lines) -> usize {
     stones.into_iter().map(|s| corridor.step(s, 25)).sum()
 }
 
+/// Keeps every stone around, only viable for the first 25 blinks
+#[aoc(day = 11, part = 1)]
+fn solve_part_1_naive(input: impl Lines) -> usize {
+    let mut stones = parse(input);
+    for _ in 0..25 {
+        stones = stones.into_iter().flat_map(step).collect();
+    }
+
+    stones.len()
+}
+
+#[aoc(day = 11, part = 2)]
 fn solve_part_2(input: impl Lines) -> usize {
     let stones = parse(input);
     let mut corridor = CachedStoneCorridor::default();
@@ -68,7 +85,7 @@ fn step(n: u64) -> StepResult {
 
     let num_digits = n.ilog10() + 1;
 
-    if num_digits % 2 == 0 {
+    if num_digits.is_multiple_of(2) {
         let exp = 10u64.pow(num_digits / 2);
         let first_half = n / exp;
         let second_half = n - first_half * exp;
@@ -86,21 +103,3 @@ fn parse(input: impl Lines + Sized) -> Vec<u64> {
         .flat_map(u64::from_str)
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "125 17";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 55312, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 55312, TEST_INPUT);
-    }
-}
@@ -1,23 +1,29 @@
-use crate::aoc::*;
-use crate::solution;
-use crate::utils::grid::{DenseGrid, XY};
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, XY};
 use rayon::prelude::*;
 use std::collections::HashSet;
 
-const DAY: Day = 12;
-
-solution!(DAY, solve_part_1, solve_part_2);
-
-fn solve_part_1(input: impl Lines) -> i64 {
-    find_regions(&parse(&input))
+#[aoc(day = 12)]
+const METADATA: Metadata = Metadata {
+    title: "Garden Groups",
+    tags: &["grid", "flood fill"],
+    uses_rayon: true,
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 12, part = 1)]
+fn solve_part_1(grid: &DenseGrid<char>) -> i64 {
+    find_regions(grid)
         .map(|(c, area)| (c, area.len() as i64, calc_perimeter(&area)))
         // .inspect(|(id, area, perimeter)| println!("{id}: area {area}, perimeter {perimeter}"))
         .map(|(_, area, perimeter)| area * perimeter)
         .sum()
 }
 
-fn solve_part_2(input: impl Lines) -> i64 {
-    find_regions(&parse(&input))
+#[aoc(day = 12, part = 2)]
+fn solve_part_2(grid: &DenseGrid<char>) -> i64 {
+    find_regions(grid)
         .map(|(c, area)| (c, area.len() as i64, calc_sides(&area)))
         // .inspect(|(id, area, sides)| println!("{id}: area {area}, sides {sides}"))
         .map(|(_, area, sides)| area * sides)
@@ -89,61 +95,7 @@ fn calc_sides(area: &HashSet<XY>) -> i64 {
         .sum()
 }
 
-fn parse(input: &impl Lines) -> DenseGrid<char> {
-    let width = input.get_lines().next().unwrap().len();
-    DenseGrid::from_iter(width, input.get_lines().flat_map(|line| line.chars()))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE";
-
-    const TEST_INPUT_2: &str = "EEEEE
-EXXXX
-EEEEE
-EXXXX
-EEEEE";
-
-    const TEST_INPUT_3: &str = "AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 1930, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 236, TEST_INPUT_2);
-    }
-
-    #[test]
-    fn test_part_2_3() {
-        aoc_test!(DAY, 2, 368, TEST_INPUT_3);
-    }
-
-    #[test]
-    fn test_simple() {
-        aoc_test!(DAY, 2, 4, "X");
-        aoc_test!(DAY, 2, 8, "XX");
-        aoc_test!(DAY, 2, 1 * 4 + 3 * 6, "OX\nXX");
-        aoc_test!(DAY, 2, 8 * 8 + 1 * 4, "XXX\nXOX\nXXX");
-        aoc_test!(DAY, 2, 2 * 4 + 2 * 4 + 2 * 4, "XOX\nXOX");
-    }
+#[aoc(day = 12, parser)]
+fn parse(input: &impl Lines) -> Result<DenseGrid<char>, ParseError> {
+    input.grid()
 }
@@ -0,0 +1,123 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::XY;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+#[aoc(day = 13)]
+const METADATA: Metadata = Metadata {
+    title: "Claw Contraption",
+    tags: &["linear algebra"],
+    complexity: Some("O(1) per machine"),
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 13, part = 1)]
+fn solve_part_1(machines: &[ClawMachine], context: &SolverContext) -> usize {
+    count_tokens(machines.iter().copied(), context)
+}
+
+#[aoc(day = 13, part = 2)]
+fn solve_part_2(machines: &[ClawMachine], context: &SolverContext) -> usize {
+    let machines = machines.iter().map(|machine| ClawMachine {
+        prize: machine.prize
+            + XY {
+                x: 10000000000000,
+                y: 10000000000000,
+            },
+        ..*machine
+    });
+    count_tokens(machines, context)
+}
+
+fn count_tokens(machines: impl Iterator<Item = ClawMachine>, context: &SolverContext) -> usize {
+    machines
+        .enumerate()
+        .filter_map(|(i, machine)| {
+            let steps = get_steps(machine);
+            match steps {
+                Some((a, b)) => context.explain(format_args!(
+                    "machine {}: {a} A + {b} B presses for {} tokens",
+                    i + 1,
+                    a * 3 + b
+                )),
+                None => context.explain(format_args!("machine {}: can't win", i + 1)),
+            }
+            steps
+        })
+        .map(|(a, b)| a * 3 + b)
+        .sum()
+}
+
+#[derive(Copy, Clone)]
+struct ClawMachine {
+    button_a: XY,
+    button_b: XY,
+    prize: XY,
+}
+
+// a * ax + b * bx = px
+// a * ay + b * by = py
+//
+// b = (py - a * ay) / by
+// a * ax + bx/by (py - a * ay) = px
+// a (ax - ay bx/by) = px - py bx/by
+// a = (px - py (bx/by)) / (ax - ay bx/by)
+// a = ((by px - py bx) / by) / ((ax by - ay bx) / by)
+// a = (by px - py bx) / (ax by - ay bx)
+
+fn get_steps(machine: ClawMachine) -> Option<(usize, usize)> {
+    let ax = machine.button_a.x;
+    let ay = machine.button_a.y;
+    let bx = machine.button_b.x;
+    let by = machine.button_b.y;
+    let px = machine.prize.x;
+    let py = machine.prize.y;
+
+    let a_nom = by * px - py * bx;
+    let a_den = ax * by - ay * bx;
+    if a_nom % a_den != 0 {
+        return None;
+    }
+
+    let a = a_nom / a_den;
+    let b_nom = py - a * ay;
+
+    if b_nom % by != 0 {
+        return None;
+    }
+
+    let b = (py - a * ay) / by;
+
+    Some((a as usize, b as usize))
+}
+
+lazy_static! {
+    static ref CLAW_REGEX: Regex = Regex::new(
+        r".*X\+(?<ax>\d+), Y\+(?<ay>\d+)\n.*X\+(?<bx>\d+), Y\+(?<by>\d+)\n.*X=(?<px>\d+), Y=(?<py>\d+)"
+    ).unwrap();
+}
+
+impl FromCaptures for ClawMachine {
+    fn from_captures(captures: &Captured) -> Result<Self, ParseError> {
+        Ok(ClawMachine {
+            button_a: XY {
+                x: captures.get("ax")?,
+                y: captures.get("ay")?,
+            },
+            button_b: XY {
+                x: captures.get("bx")?,
+                y: captures.get("by")?,
+            },
+            prize: XY {
+                x: captures.get("px")?,
+                y: captures.get("py")?,
+            },
+        })
+    }
+}
+
+#[aoc(day = 13, parser)]
+fn parse(input: impl Lines) -> Result<Vec<ClawMachine>, ParseError> {
+    input.captures_into(&CLAW_REGEX)
+}
@@ -0,0 +1,210 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, XY};
+use aoc_framework::visualize::{self, Color, Frame};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+use std::iter;
+
+#[aoc(day = 14)]
+const METADATA: Metadata = Metadata {
+    title: "Restroom Redoubt",
+    tags: &["simulation", "heuristic"],
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 14, part = 1)]
+fn solve_part_1(input: impl Lines) -> Result<usize, SolveError> {
+    let w = odd_param(&input, "width", 101)?;
+    let h = odd_param(&input, "height", 103)?;
+    let steps = input.param("steps", 100)?;
+
+    let robots = parse(&input)?;
+    let context = input.get_context();
+    if context.is_recording() {
+        for step in 0..=steps {
+            context.frame(|| {
+                let positions = robots.iter().map(|robot| evolve_robot(robot, w, h, step).p);
+                render(positions, w, h)
+            });
+        }
+    }
+
+    Ok(solve(&robots, w, h, steps))
+}
+
+#[aoc(day = 14, part = 2)]
+fn solve_part_2(input: impl Lines) -> Result<usize, SolveError> {
+    let w = input.param("width", 101)?;
+    let h = input.param("height", 103)?;
+
+    let robots = parse(&input)?;
+    let min_inertia = 500000; // LOL, LMAO even
+
+    for steps in 0.. {
+        if steps % 100 == 0 {
+            input.get_context().check_cancelled()?;
+        }
+        let mut positions = HashSet::new();
+        for robot in &robots {
+            let pos = evolve_robot(robot, w, h, steps).p;
+            positions.insert(pos);
+        }
+
+        let current_inertia = inertia(&positions);
+        input
+            .get_context()
+            .frame(|| render(positions.iter().copied(), w, h));
+
+        if current_inertia < min_inertia {
+            let context = input.get_context();
+            if context.enabled(Verbosity::Debug) {
+                let mut grid = DenseGrid::from_iter(w, iter::repeat_n('.', w * h));
+                for pos in positions {
+                    grid.try_set_at(pos.as_tuple(), 'X').unwrap();
+                }
+                context.debug(grid);
+                context.debug(format_args!("{steps}, inertia: {current_inertia}"));
+            }
+
+            return Ok(steps);
+        }
+    }
+
+    unreachable!()
+}
+
+fn render(positions: impl Iterator<Item = XY>, w: usize, h: usize) -> Frame {
+    let mut grid = DenseGrid::from_iter(w, iter::repeat_n(false, w * h));
+    for pos in positions {
+        grid.set_at(pos.as_tuple(), true);
+    }
+    visualize::frame(&grid, |&robot| {
+        if robot {
+            ('#', Color::Green)
+        } else {
+            ('.', Color::Default)
+        }
+    })
+}
+
+fn inertia(positions: &HashSet<XY>) -> i64 {
+    let pos_sum = positions.iter().cloned().reduce(|acc, p| acc + p).unwrap();
+    let center_of_mass = XY {
+        x: pos_sum.x / (positions.len() as i64),
+        y: pos_sum.y / (positions.len() as i64),
+    };
+    positions
+        .iter()
+        .map(|p| (center_of_mass - *p).length_sq())
+        .sum()
+}
+
+fn solve(robots: &[Robot], w: usize, h: usize, steps: usize) -> usize {
+    robots
+        .iter()
+        .map(|robot| evolve_robot(robot, w, h, steps))
+        .flat_map(|robot| get_quadrant(&robot.p, w, h))
+        .zip(iter::repeat(1))
+        .into_grouping_map()
+        .sum()
+        .values()
+        .product()
+}
+
+/// Reads a size of the room, which must be odd for the quadrants to have a middle line between
+/// them.
+fn odd_param(input: &impl Lines, name: &str, default: usize) -> Result<usize, SolveError> {
+    let size = input.param(name, default)?;
+    if size % 2 == 0 {
+        return Err(SolveError::InvalidParam {
+            name: name.to_owned(),
+            message: format!("{size} isn't odd"),
+        });
+    }
+    Ok(size)
+}
+
+fn evolve_robot(robot: &Robot, width: usize, height: usize, steps: usize) -> Robot {
+    let new_pos = robot.p + robot.v * (steps as i64);
+    Robot {
+        p: XY {
+            x: wrap(new_pos.x, width as i64),
+            y: wrap(new_pos.y, height as i64),
+        },
+        v: robot.v,
+    }
+}
+
+fn get_quadrant(p: &XY, width: usize, height: usize) -> Option<usize> {
+    assert_eq!(width % 2, 1);
+    assert_eq!(height % 2, 1);
+    let middle_x = (width / 2) as i64;
+    let middle_y = (height / 2) as i64;
+    if p.x < middle_x && p.y < middle_y {
+        Some(0)
+    } else if p.x > middle_x && p.y < middle_y {
+        Some(1)
+    } else if p.x < middle_x && p.y > middle_y {
+        Some(2)
+    } else if p.x > middle_x && p.y > middle_y {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+fn wrap(value: i64, bound: i64) -> i64 {
+    if value < 0 {
+        (value % bound + bound) % bound
+    } else {
+        value % bound
+    }
+}
+
+lazy_static! {
+    static ref LINE_REGEX: Regex =
+        Regex::new(r"p=(?<px>\d+),(?<py>\d+) v=(?<vx>-?\d+),(?<vy>-?\d+)").unwrap();
+}
+
+struct Robot {
+    p: XY,
+    v: XY,
+}
+
+impl FromCaptures for Robot {
+    fn from_captures(captures: &Captured) -> Result<Self, ParseError> {
+        Ok(Robot {
+            p: XY {
+                x: captures.get("px")?,
+                y: captures.get("py")?,
+            },
+            v: XY {
+                x: captures.get("vx")?,
+                y: captures.get("vy")?,
+            },
+        })
+    }
+}
+
+fn parse(input: &impl Lines) -> Result<Vec<Robot>, ParseError> {
+    input.captures_into(&LINE_REGEX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_even_size() {
+        let input = PuzzleInput::from("p=0,4 v=3,-3\n")
+            .with_param("width", 10)
+            .with_param("height", 7);
+        assert!(matches!(
+            solve_part_1(input),
+            Err(SolveError::InvalidParam { name, .. }) if name == "width"
+        ));
+    }
+}
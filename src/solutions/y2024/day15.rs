@@ -1,39 +1,61 @@
-use crate::aoc::*;
-use crate::solution;
-use crate::utils::grid::{DenseGrid, Direction, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, Direction, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use aoc_framework::visualize::{self, Color, Frame};
+use itertools::Itertools;
 use std::collections::HashSet;
-use std::fmt::Formatter;
-
-const DAY: Day = 15;
-
-solution!(DAY, solve_part_1, solve_part_2);
-
-fn solve_part_1(input: impl Lines) -> i64 {
-    let (mut grid, moves) = parse(&input, false);
-    solve(&mut grid, moves);
-    score(&grid)
+use std::fmt::{Display, Formatter};
+
+#[aoc(day = 15)]
+const METADATA: Metadata = Metadata {
+    title: "Warehouse Woes",
+    tags: &["grid", "simulation"],
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 15, part = 1)]
+fn solve_part_1(input: impl Lines) -> Result<i64, ParseError> {
+    let (mut grid, robot, moves) = parse(&input, false)?;
+    solve(&mut grid, robot, moves, input.get_context());
+    Ok(score(&grid))
 }
 
-fn solve_part_2(input: impl Lines) -> i64 {
-    let (mut grid, moves) = parse(&input, true);
-    solve(&mut grid, moves);
-    score(&grid)
+#[aoc(day = 15, part = 2)]
+fn solve_part_2(input: impl Lines) -> Result<i64, ParseError> {
+    let (mut grid, robot, moves) = parse(&input, true)?;
+    solve(&mut grid, robot, moves, input.get_context());
+    Ok(score(&grid))
 }
 
-fn solve(grid: &mut DenseGrid<Tile>, moves: Vec<Direction>) {
-    let mut robot_position = grid.find(&Tile::Robot).next().unwrap();
+fn solve(
+    grid: &mut DenseGrid<Tile>,
+    mut robot_position: XY,
+    moves: Vec<Direction>,
+    context: &SolverContext,
+) {
+    context.frame(|| render(grid));
     for direction in moves {
         if let Some(actions) = plan_moves(grid, robot_position, direction, &mut HashSet::new()) {
             robot_position = robot_position + direction;
             for action in actions {
                 action(grid);
             }
-            // println!("// {direction:?}");
-            // println!("{grid}");
         }
+        context.frame(|| render(grid));
     }
 }
 
+fn render(grid: &DenseGrid<Tile>) -> Frame {
+    visualize::frame(grid, |tile| match tile {
+        Tile::Wall => ('#', Color::Gray),
+        Tile::Box => ('O', Color::Yellow),
+        Tile::LeftBox => ('[', Color::Yellow),
+        Tile::RightBox => (']', Color::Yellow),
+        Tile::Robot => ('@', Color::Red),
+        Tile::Empty => ('.', Color::Default),
+    })
+}
+
 fn score(grid: &DenseGrid<Tile>) -> i64 {
     grid.find(&Tile::Box)
         .chain(grid.find(&Tile::LeftBox))
@@ -110,48 +132,46 @@ enum Tile {
     Empty,
 }
 
-fn parse(input: &impl Lines, double: bool) -> (DenseGrid<Tile>, Vec<Direction>) {
-    let width = input.get_lines().next().unwrap().len() * if double { 2 } else { 1 };
-    let mut iter = input.get_lines();
-    let tiles = iter
-        .by_ref()
-        .take_while(|&l| !l.is_empty())
-        .flat_map(str::chars)
-        .flat_map(Tile::try_from)
-        .flat_map(|tile| {
-            if double {
-                match tile {
-                    Tile::Box => [Some(Tile::LeftBox), Some(Tile::RightBox)],
-                    Tile::Robot => [Some(Tile::Robot), Some(Tile::Empty)],
-                    Tile::Empty => [Some(Tile::Empty), Some(Tile::Empty)],
-                    Tile::Wall => [Some(Tile::Wall), Some(Tile::Wall)],
-                    Tile::LeftBox => {
-                        unreachable!();
-                    }
-                    Tile::RightBox => {
-                        unreachable!();
-                    }
-                }
-            } else {
-                [Some(tile), None]
-            }
-        })
-        .flatten();
-
-    let grid = DenseGrid::from_iter(width, tiles);
-
-    let moves = iter
-        .flat_map(str::chars)
-        .flat_map(|c| match c {
-            '^' => Some(DIR_UP),
-            '>' => Some(DIR_RIGHT),
-            'v' => Some(DIR_DOWN),
-            '<' => Some(DIR_LEFT),
-            _ => None,
+fn parse(
+    input: &impl Lines,
+    double: bool,
+) -> Result<(DenseGrid<Tile>, XY, Vec<Direction>), ParseError> {
+    let (map, moves) = input
+        .sections()
+        .collect_tuple()
+        .ok_or_else(|| input.parse_error("", "expected the map and the moves"))?;
+
+    let mut grid: DenseGrid<Tile> = map.grid()?;
+    let mut robot = map.grid_position('@')?;
+    if double {
+        robot.x *= 2;
+        let tiles = grid
+            .rows()
+            .flatten()
+            .flat_map(|tile| match tile {
+                Tile::Box => [Tile::LeftBox, Tile::RightBox],
+                Tile::Robot => [Tile::Robot, Tile::Empty],
+                Tile::Empty => [Tile::Empty, Tile::Empty],
+                Tile::Wall => [Tile::Wall, Tile::Wall],
+                Tile::LeftBox | Tile::RightBox => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        grid = DenseGrid::from_iter(grid.width() as usize * 2, tiles.into_iter());
+    }
+
+    let moves = moves
+        .get_lines()
+        .flat_map(|line| line.char_indices().map(move |(i, c)| (&line[i..], c)))
+        .map(|(token, c)| match c {
+            '^' => Ok(DIR_UP),
+            '>' => Ok(DIR_RIGHT),
+            'v' => Ok(DIR_DOWN),
+            '<' => Ok(DIR_LEFT),
+            _ => Err(moves.parse_error(token, format!("invalid move {c:?}"))),
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    (grid, moves)
+    Ok((grid, robot, moves))
 }
 
 impl TryFrom<char> for Tile {
@@ -180,41 +200,3 @@ impl Display for Tile {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "##########
-#..O..O.O#
-#......O.#
-#.OO..O.O#
-#..O@..O.#
-#O#..O...#
-#O..O..O.#
-#.OO.O.OO#
-#....O...#
-##########
-
-<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
->^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 10092, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 9021, TEST_INPUT);
-    }
-}
@@ -0,0 +1,167 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, Direction, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+#[aoc(day = 16)]
+const METADATA: Metadata = Metadata {
+    title: "Reindeer Maze",
+    tags: &["grid", "pathfinding"],
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 16, part = 1)]
+fn solve_part_1(maze: &Maze, context: &SolverContext) -> Result<i64, SolveError> {
+    let (map, start_position, end_position) = (&maze.grid, maze.start, maze.end);
+    let (path, cost) = pathfinding::directed::astar::astar(
+        &Node {
+            position: start_position,
+            facing: DIR_RIGHT,
+        },
+        |node| successors(node, map),
+        |node| (node.position - end_position).taxicab_length(),
+        |node| node.position == end_position,
+    )
+    .ok_or(SolveError::Runtime("no path to the end tile".to_owned()))?;
+
+    if context.is_explaining() {
+        let facing: HashMap<XY, Direction> = path
+            .iter()
+            .map(|node| (node.position, node.facing))
+            .collect();
+        context.explain(map.render(|position, &tile| match facing.get(&position) {
+            Some(&facing) if tile == Tile::Empty => direction_char(facing),
+            _ => tile.into(),
+        }));
+    }
+
+    Ok(cost)
+}
+
+#[aoc(day = 16, part = 2)]
+fn solve_part_2(maze: &Maze, context: &SolverContext) -> Result<usize, SolveError> {
+    let (map, start_position, end_position) = (&maze.grid, maze.start, maze.end);
+    let (solution, _) = pathfinding::directed::astar::astar_bag(
+        &Node {
+            position: start_position,
+            facing: DIR_RIGHT,
+        },
+        |node| successors(node, map),
+        |node| (node.position - end_position).taxicab_length(),
+        |node| node.position == end_position,
+    )
+    .ok_or(SolveError::Runtime("no path to the end tile".to_owned()))?;
+
+    let seats: HashSet<XY> = solution
+        .into_iter()
+        .flatten()
+        .map(|node| node.position)
+        .collect();
+    if context.is_explaining() {
+        context.explain(map.render(|position, &tile| {
+            if tile == Tile::Empty && seats.contains(&position) {
+                'O'
+            } else {
+                tile.into()
+            }
+        }));
+    }
+
+    Ok(seats.len())
+}
+
+fn direction_char(direction: Direction) -> char {
+    match direction {
+        DIR_UP => '^',
+        DIR_DOWN => 'v',
+        DIR_LEFT => '<',
+        _ => '>',
+    }
+}
+
+fn successors(node: &Node, map: &DenseGrid<Tile>) -> Vec<(Node, i64)> {
+    // you can always turn around in place
+    let mut result = [DIR_UP, DIR_DOWN, DIR_LEFT, DIR_RIGHT]
+        .into_iter()
+        .filter(|dir| dir != &node.facing)
+        .map(|dir| {
+            (
+                Node {
+                    position: node.position,
+                    facing: dir,
+                },
+                1000,
+            )
+        })
+        .collect_vec();
+
+    // try to move forwards
+    if map.at((node.position + node.facing).as_tuple()) != Some(&Tile::Wall) {
+        result.push((
+            Node {
+                position: node.position + node.facing,
+                facing: node.facing,
+            },
+            1, // with a cost of 1
+        ))
+    };
+
+    result
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Node {
+    position: XY,
+    facing: Direction,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Tile {
+    Wall,
+    Start,
+    End,
+    Empty,
+}
+
+/// The map of the maze, with where the path starts and ends.
+#[derive(Debug, Clone)]
+pub struct Maze {
+    pub grid: DenseGrid<Tile>,
+    pub start: XY,
+    pub end: XY,
+}
+
+#[aoc(day = 16, parser)]
+pub fn parse(input: &impl Lines) -> Result<Maze, ParseError> {
+    Ok(Maze {
+        grid: input.grid()?,
+        start: input.grid_position('S')?,
+        end: input.grid_position('E')?,
+    })
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> char {
+        match tile {
+            Tile::Wall => '#',
+            Tile::Empty => '.',
+            Tile::Start => 'S',
+            Tile::End => 'E',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Empty),
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            _ => Err(()),
+        }
+    }
+}
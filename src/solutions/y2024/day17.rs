@@ -1,15 +1,19 @@
-use crate::aoc::*;
-use crate::solution;
+use super::YEAR;
+use aoc_framework::aoc::*;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 
-const DAY: Day = 17;
+#[aoc(day = 17)]
+const METADATA: Metadata = Metadata {
+    title: "Chronospatial Computer",
+    tags: &["virtual machine"],
+    ..Metadata::EMPTY
+};
 
-solution!(DAY, solve_part_1);
-
-fn solve_part_1(input: impl Lines) -> String {
-    let mut computer = parse(&input);
+#[aoc(day = 17, part = 1)]
+fn solve_part_1(input: impl Lines) -> Result<String, ParseError> {
+    let mut computer = parse(&input)?;
     let mut results = vec![];
 
     loop {
@@ -24,17 +28,19 @@ fn solve_part_1(input: impl Lines) -> String {
         }
     }
 
-    results.iter().join(",")
+    Ok(results.iter().join(","))
 }
 
 #[allow(unused)]
-fn solve_part_2(input: impl Lines) -> i64 {
-    let mut computer = parse(&input);
+fn solve_part_2(input: impl Lines) -> Result<i64, SolveError> {
+    let mut computer = parse(&input)?;
+    let context = input.get_context();
 
     for a in 0.. {
         if a % 1_000_000 == 0 {
-            println!("a: {a}");
+            context.progress(format_args!("a: {a}"));
         }
+        context.check_cancelled()?;
 
         computer.state = State {
             a,
@@ -59,11 +65,11 @@ fn solve_part_2(input: impl Lines) -> i64 {
         }
 
         if !terminated {
-            println!("iteration limit")
+            context.debug(format_args!("a: {a} reached the iteration limit"));
         }
 
         if results == computer.program {
-            return a;
+            return Ok(a);
         }
     }
 
@@ -176,80 +182,32 @@ struct Computer {
 }
 
 lazy_static! {
-    static ref INPUT_REGEX_A: Regex = Regex::new(r"A: (\d+)").unwrap();
-    static ref INPUT_REGEX_B: Regex = Regex::new(r"B: (\d+)").unwrap();
-    static ref INPUT_REGEX_C: Regex = Regex::new(r"C: (\d+)").unwrap();
-    static ref INPUT_REGEX_PROG: Regex = Regex::new(r"Program: ([\d,]+)").unwrap();
+    static ref INPUT_REGEX: Regex =
+        Regex::new(r"A: (?<a>\d+)\s+.*B: (?<b>\d+)\s+.*C: (?<c>\d+)\s+Program: (?<program>[\d,]+)")
+            .unwrap();
 }
 
-fn parse(input: &impl Lines) -> Computer {
-    let a: i64 = INPUT_REGEX_A
-        .captures(input.get_raw())
-        .unwrap()
-        .get(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
-
-    let b: i64 = INPUT_REGEX_B
-        .captures(input.get_raw())
-        .unwrap()
-        .get(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
-
-    let c: i64 = INPUT_REGEX_C
-        .captures(input.get_raw())
-        .unwrap()
-        .get(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
-
-    let program = INPUT_REGEX_PROG
-        .captures(input.get_raw())
-        .unwrap()
-        .get(1)
-        .unwrap()
-        .as_str()
-        .split(',')
-        .map(|s| s.parse::<i64>().unwrap())
-        .collect_vec();
-
-    Computer {
-        state: State { ip: 0, a, b, c },
-        program,
+impl FromCaptures for Computer {
+    fn from_captures(captures: &Captured) -> Result<Self, ParseError> {
+        let program = captures.text("program")?;
+        Ok(Computer {
+            state: State {
+                ip: 0,
+                a: captures.get("a")?,
+                b: captures.get("b")?,
+                c: captures.get("c")?,
+            },
+            program: program
+                .split(',')
+                .map(|n| n.parse().map_err(|e| captures.parse_error(n, e)))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "Register A: 729
-Register B: 0
-Register C: 0
-
-Program: 0,1,5,4,3,0";
-
-    const TEST_INPUT_2: &str = "Register A: 2024
-Register B: 0
-Register C: 0
-
-Program: 0,3,5,4,3,0";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, "4,6,3,5,6,3,5,2,1,0", TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 117440, TEST_INPUT_2);
-    }
+fn parse(input: &impl Lines) -> Result<Computer, ParseError> {
+    input
+        .captures_into(&INPUT_REGEX)?
+        .pop()
+        .ok_or_else(|| input.parse_error("", "expected the registers and the program"))
 }
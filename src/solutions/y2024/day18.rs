@@ -0,0 +1,154 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use itertools::Itertools;
+use pathfinding::prelude::astar;
+use std::collections::HashSet;
+
+#[aoc(day = 18)]
+const METADATA: Metadata = Metadata {
+    title: "RAM Run",
+    tags: &["grid", "pathfinding", "binary search"],
+    complexity: Some("O(log bytes) searches"),
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 18, part = 1)]
+fn solve_part_1(input: impl Lines) -> Result<i64, SolveError> {
+    let width = input.param("width", 71)?;
+    let height = input.param("height", 71)?;
+    let bytes = input.param("bytes", 1024)?;
+    let falling_bytes = parse(&input)?;
+    let fallen_bytes = falling_bytes.get(0..bytes).ok_or_else(|| {
+        SolveError::Runtime(format!("only {} bytes are falling", falling_bytes.len()))
+    })?;
+    solve(width, height, fallen_bytes)
+        .map(|(_, cost)| cost)
+        .ok_or(SolveError::Runtime("no path to the exit".to_owned()))
+}
+
+#[aoc(day = 18, part = 2)]
+fn solve_part_2(input: impl Lines) -> Result<String, SolveError> {
+    let width = input.param("width", 71)?;
+    let height = input.param("height", 71)?;
+    let falling_bytes = parse(&input)?;
+    let p = get_unreachable(width, height, &falling_bytes)?;
+    Ok(format!("{},{}", p.x, p.y))
+}
+
+/// Drops the bytes one at a time, to check the binary search.
+#[aoc(day = 18, part = 2)]
+fn solve_part_2_linear(input: impl Lines) -> Result<String, SolveError> {
+    let width = input.param("width", 71)?;
+    let height = input.param("height", 71)?;
+    let falling_bytes = parse(&input)?;
+    (1..=falling_bytes.len())
+        .find(|&fallen| solve(width, height, &falling_bytes[..fallen]).is_none())
+        .map(|fallen| {
+            let p = falling_bytes[fallen - 1];
+            format!("{},{}", p.x, p.y)
+        })
+        .ok_or(SolveError::Runtime("the exit is never cut off".to_owned()))
+}
+
+fn get_unreachable(width: usize, height: usize, falling_bytes: &[XY]) -> Result<XY, SolveError> {
+    let cuts_off = |fallen: usize| solve(width, height, &falling_bytes[..fallen]).is_none();
+    if !cuts_off(falling_bytes.len()) {
+        return Err(SolveError::Runtime("the exit is never cut off".to_owned()));
+    }
+    // binary search, the first `lower` bytes leave a path to the exit and the first `upper` don't
+    let (mut lower, mut upper) = (0, falling_bytes.len());
+    while upper - lower > 1 {
+        let middle = (lower + upper) / 2;
+        if cuts_off(middle) {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+    Ok(falling_bytes[upper - 1])
+}
+
+fn solve(width: usize, height: usize, fallen_bytes: &[XY]) -> Option<(Vec<XY>, i64)> {
+    let target_p = XY {
+        x: (width - 1) as i64,
+        y: (height - 1) as i64,
+    };
+    let obstacles = HashSet::from_iter(fallen_bytes.iter().copied());
+    astar(
+        &XY { x: 0, y: 0 },
+        |p| successors(*p, width, height, &obstacles),
+        |p| (*p - target_p).taxicab_length(),
+        |p| *p == target_p,
+    )
+}
+
+fn successors(p: XY, width: usize, height: usize, obstacles: &HashSet<XY>) -> Vec<(XY, i64)> {
+    [DIR_UP, DIR_DOWN, DIR_LEFT, DIR_RIGHT]
+        .iter()
+        .filter_map(|d| {
+            let next_p = p + *d;
+            if (0..width as i64).contains(&next_p.x)
+                && (0..height as i64).contains(&next_p.y)
+                && !obstacles.contains(&next_p)
+            {
+                Some((next_p, 1))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Every cell of a `size` by `size` grid but the start and the exit, falling in a random order.
+#[aoc(day = 18, generator)]
+fn generate(random: &mut Rng, size: usize) -> PuzzleInput {
+    let size = size.max(3);
+    let mut cells = (0..size)
+        .cartesian_product(0..size)
+        .filter(|&cell| cell != (0, 0) && cell != (size - 1, size - 1))
+        .collect_vec();
+    random.shuffle(&mut cells);
+    let bytes = cells.len() / 4;
+    let input = cells
+        .into_iter()
+        .map(|(x, y)| format!("{x},{y}\n"))
+        .collect::<String>();
+    PuzzleInput::from(input)
+        .with_param("width", size)
+        .with_param("height", size)
+        .with_param("bytes", bytes)
+}
+
+fn parse(input: &impl Lines) -> Result<Vec<XY>, ParseError> {
+    input
+        .get_lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (x, y) = input
+                .ints_in(line)?
+                .into_iter()
+                .collect_tuple()
+                .ok_or_else(|| input.parse_error(line, "expected a position like 6,1"))?;
+            Ok(XY { x, y })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let input = PuzzleInput::from("5,4\n4\n");
+        let Err(error) = parse(&input) else {
+            panic!("a line with one number should be rejected");
+        };
+        assert_eq!((error.line, error.column), (2, 1));
+        let empty = PuzzleInput::from("")
+            .with_param("width", 7)
+            .with_param("height", 7);
+        assert!(matches!(solve_part_2(empty), Err(SolveError::Runtime(_))));
+    }
+}
@@ -0,0 +1,78 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+use regex::Regex;
+use smol_str::SmolStr;
+use std::collections::{HashMap, HashSet};
+
+#[aoc(day = 19)]
+const METADATA: Metadata = Metadata {
+    title: "Linen Layout",
+    tags: &["dp", "memoization", "regex"],
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 19, part = 1)]
+fn solve_part_1(input: impl Lines) -> Result<usize, ParseError> {
+    let (patterns, designs) = parse(&input)?;
+    let regex = build_regex(&patterns);
+    Ok(designs.filter(|line| regex.is_match(line)).count())
+}
+
+#[aoc(day = 19, part = 2)]
+fn solve_part_2(input: impl Lines) -> Result<usize, ParseError> {
+    let (patterns, designs) = parse(&input)?;
+    let regex = build_regex(&patterns);
+    let mut cache = HashMap::new();
+
+    Ok(designs
+        .filter(|line| regex.is_match(line))
+        .map(|line| combinations(line, &patterns, &mut cache))
+        .sum())
+}
+
+fn combinations(s: &str, options: &HashSet<SmolStr>, cache: &mut HashMap<SmolStr, usize>) -> usize {
+    if s.is_empty() {
+        return 1;
+    }
+
+    if let Some(&count) = cache.get(s) {
+        return count;
+    }
+
+    let total = options
+        .iter()
+        .map(|part| {
+            if s.starts_with(part.as_str()) {
+                combinations(&s[part.len()..], options, cache)
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    if total != 0 {
+        cache.insert(s.into(), total);
+    }
+
+    total
+}
+
+fn build_regex(patterns: &HashSet<SmolStr>) -> Regex {
+    Regex::new(&format!("^({})+$", patterns.iter().sorted().join("|"))).unwrap()
+}
+
+fn parse(input: &impl Lines) -> Result<(HashSet<SmolStr>, impl Iterator<Item = &str>), ParseError> {
+    let (patterns, designs) = input
+        .sections()
+        .collect_tuple()
+        .ok_or_else(|| input.parse_error("", "expected the patterns and the designs"))?;
+    let patterns = patterns
+        .as_str()
+        .trim()
+        .split(", ")
+        .map(SmolStr::from)
+        .collect();
+
+    Ok((patterns, designs.as_str().lines().map(str::trim)))
+}
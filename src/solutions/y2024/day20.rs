@@ -1,24 +1,32 @@
 #![allow(unused)]
-use crate::aoc::*;
-use crate::solution;
-use crate::solutions::day16;
-use crate::solutions::day16::Tile;
-use crate::utils::grid::{DenseGrid, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
+use super::YEAR;
+use crate::solutions::y2024::day16;
+use crate::solutions::y2024::day16::{Maze, Tile};
+use aoc_framework::aoc::*;
+use aoc_framework::utils::grid::{DenseGrid, DIR_DOWN, DIR_LEFT, DIR_RIGHT, DIR_UP, XY};
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashSet;
 use std::hash::Hash;
 use std::iter;
 
-const DAY: Day = 20;
-
-solution!(DAY, solve_part_1);
+#[aoc(day = 20)]
+const METADATA: Metadata = Metadata {
+    title: "Race Condition",
+    tags: &["grid", "pathfinding"],
+    uses_rayon: true,
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 20, parser)]
+fn parse(input: impl Lines) -> Result<Maze, ParseError> {
+    day16::parse(&input)
+}
 
-fn solve_part_1(input: impl Lines) -> usize {
-    let grid = day16::parse(&input);
-    let start_pos = grid.find(&Tile::Start).exactly_one().ok().unwrap();
-    let end_pos = grid.find(&Tile::End).exactly_one().ok().unwrap();
-    let (initial_path, initial_cost) = find_shortest_path(&grid, start_pos, end_pos);
+#[aoc(day = 20, part = 1)]
+fn solve_part_1(track: &Maze) -> usize {
+    let (grid, start_pos, end_pos) = (&track.grid, track.start, track.end);
+    let (initial_path, initial_cost) = find_shortest_path(grid, start_pos, end_pos);
     let cheats = (0..grid.width())
         .cartesian_product(0..grid.height())
         .map(XY::from)
@@ -51,14 +59,19 @@ fn solve_part_1(input: impl Lines) -> usize {
     cheats.filter(|(time_save, _)| *time_save >= 100).count()
 }
 
-fn solve_part_2(input: impl Lines) -> usize {
-    let grid = day16::parse(&input);
-    get_shortcuts(&grid, 2).for_each(|(cheat, time_save)| println!("{cheat:?} saves {time_save}"));
+fn solve_part_2(track: &Maze, context: &SolverContext) -> usize {
+    get_shortcuts(track, 2, context)
+        .for_each(|(cheat, time_save)| context.debug(format_args!("{cheat:?} saves {time_save}")));
 
     0
 }
 
-fn solve<FN, FS, IN>(start: Node, successors: FN, success: FS) -> Vec<(Node, i64)>
+fn solve<FN, FS, IN>(
+    start: Node,
+    successors: FN,
+    success: FS,
+    context: &SolverContext,
+) -> Vec<(Node, i64)>
 where
     FN: Fn(&Node, i64) -> IN,
     IN: IntoIterator<Item = (Node, i64)>,
@@ -72,8 +85,12 @@ where
             continue;
         }
         visited.insert(node);
-        if visited.len() % 1_000_000 == 0 {
-            println!("visited {}, pending {}", visited.len(), pending.len());
+        if visited.len().is_multiple_of(1_000_000) {
+            context.progress(format_args!(
+                "visited {}, pending {}",
+                visited.len(),
+                pending.len()
+            ));
         }
         if success(&node) {
             goals.push((node, cost));
@@ -87,43 +104,43 @@ where
     goals
 }
 
-fn get_shortcuts(
-    grid: &DenseGrid<Tile>,
+fn get_shortcuts<'a>(
+    track: &'a Maze,
     max_length: usize,
-) -> impl Iterator<Item = (SimpleCheat, i64)> + use<'_> {
-    let start_pos = grid.find(&Tile::Start).exactly_one().ok().unwrap();
-    let end_pos = grid.find(&Tile::End).exactly_one().ok().unwrap();
+    context: &'a SolverContext,
+) -> impl Iterator<Item = (SimpleCheat, i64)> + use<'a> {
+    let (grid, start_pos, end_pos) = (&track.grid, track.start, track.end);
     let (path, _) = find_shortest_path(grid, start_pos, end_pos);
-    println!("{path:?}");
+    context.debug(format_args!("{path:?}"));
     path.clone()
         .into_iter()
         .permutations(2)
-        .inspect(|p| println!("{p:?}"))
+        .inspect(|p| context.debug(format_args!("{p:?}")))
         .filter_map(move |p| {
             let [a, b] = p.try_into().unwrap();
             let (cost_a, _) = path.iter().find_position(|x| **x == a).unwrap();
             let (cost_b, _) = path.iter().find_position(|x| **x == b).unwrap();
             if cost_b <= cost_a {
-                println!("invalid order: {cost_a} {cost_b}");
+                context.debug(format_args!("invalid order: {cost_a} {cost_b}"));
                 return None;
             }
             let dt = (cost_b as i64) - (cost_a as i64);
-            println!("dt: {dt}");
+            context.debug(format_args!("dt: {dt}"));
             pathfinding::directed::dijkstra::dijkstra(
                 &a,
                 |p| wall_successors(grid, *p, end_pos),
                 |p| *p == b,
             )
-            .inspect(|p| println!("{p:?}"))
+            .inspect(|p| context.debug(format_args!("{p:?}")))
             .and_then(|(_, cost)| {
                 if cost > dt {
-                    println!("doesn't save time");
+                    context.debug("doesn't save time");
                     None
                 } else if cost > max_length as i64 {
-                    println!("path is too long {cost}");
+                    context.debug(format_args!("path is too long {cost}"));
                     None
                 } else {
-                    println!("saves {}", dt - cost);
+                    context.debug(format_args!("saves {}", dt - cost));
                     Some((SimpleCheat(a, b), dt - cost))
                 }
             })
@@ -165,35 +182,3 @@ enum Cheat {
     Active { start: XY, remaining: usize },
     Used { start: XY, end: XY },
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "###############
-#...#...#.....#
-#.#.#.#.#.###.#
-#S#...#.#.#...#
-#######.#.#.###
-#######.#.#...#
-#######.#.###.#
-###..E#...#...#
-###.#######.###
-#...###...#...#
-#.#####.#.###.#
-#.#...#.#.#...#
-#.#.#.#.#.#.###
-#...#...#...###
-###############";
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 0, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 0, TEST_INPUT);
-    }
-}
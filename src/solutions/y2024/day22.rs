@@ -1,22 +1,32 @@
-use crate::aoc::*;
-use crate::solution;
+use super::YEAR;
+use aoc_framework::aoc::*;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashMap;
 use std::iter;
 
-const DAY: Day = 22;
-
-solution!(DAY, solve_part_1, solve_part_2);
-
-fn solve_part_1(input: impl Lines) -> i64 {
-    parse(&input)
+#[aoc(day = 22)]
+const METADATA: Metadata = Metadata {
+    title: "Monkey Market",
+    tags: &["simulation", "hashing"],
+    uses_rayon: true,
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 22, part = 1)]
+fn solve_part_1(secrets: &[i64]) -> i64 {
+    secrets
+        .iter()
+        .copied()
         .map(|secret| SecretNumber { secret }.nth(2000).unwrap())
         .sum()
 }
 
-fn solve_part_2(input: impl Lines) -> i64 {
-    let best_values: Vec<_> = parse(&input)
+#[aoc(day = 22, part = 2)]
+fn solve_part_2(secrets: &[i64]) -> i64 {
+    let best_values: Vec<_> = secrets
+        .iter()
+        .copied()
         .map(|n| (n, best_value_by_sequence(n, 2000)))
         .collect();
 
@@ -27,6 +37,18 @@ fn solve_part_2(input: impl Lines) -> i64 {
         .unwrap()
 }
 
+/// Adds up the price of each sequence as it's first seen, instead of trying every sequence.
+#[aoc(day = 22, part = 2)]
+fn solve_part_2_totals(secrets: &[i64]) -> i64 {
+    let mut totals: HashMap<[i8; 4], i64> = HashMap::new();
+    for &secret in secrets {
+        for (sequence, price) in best_value_by_sequence(secret, 2000) {
+            *totals.entry(sequence).or_default() += price;
+        }
+    }
+    totals.into_values().max().unwrap_or(0)
+}
+
 fn sum_for_sequence<'a>(
     best_values: impl Iterator<Item = &'a HashMap<[i8; 4], i64>>,
     sequence: &[i8; 4],
@@ -80,26 +102,22 @@ impl Iterator for SecretNumber {
     }
 }
 
-fn parse(input: &impl Lines) -> impl Iterator<Item = i64> + '_ {
-    input
-        .get_lines()
-        .filter_map(|line| line.parse::<i64>().ok())
+/// Initial secrets anywhere in the range of the secrets.
+#[aoc(day = 22, generator)]
+fn generate(random: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", random.i64(1..16777216)))
+        .collect()
+}
+
+#[aoc(day = 22, parser)]
+fn parse(input: &impl Lines) -> Result<Vec<i64>, ParseError> {
+    Ok(input.ints()?.into_iter().flatten().collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::aoc_test;
-
-    const TEST_INPUT: &str = "1
-10
-100
-2024";
-
-    const TEST_INPUT_2: &str = "1
-2
-3
-2024";
 
     #[test]
     fn test_secret() {
@@ -137,14 +155,4 @@ mod tests {
             .for_each(|v| println!("{:?}", v.get(&sequence)));
         assert_eq!(sum_for_sequence(best_values.iter(), &sequence), 23);
     }
-
-    #[test]
-    fn test_part_1() {
-        aoc_test!(DAY, 1, 37327623, TEST_INPUT);
-    }
-
-    #[test]
-    fn test_part_2() {
-        aoc_test!(DAY, 2, 23, TEST_INPUT_2);
-    }
 }
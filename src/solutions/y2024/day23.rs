@@ -0,0 +1,124 @@
+use super::YEAR;
+use aoc_framework::aoc::*;
+use itertools::Itertools;
+use smol_str::SmolStr;
+use std::collections::{HashMap, HashSet};
+
+#[aoc(day = 23)]
+const METADATA: Metadata = Metadata {
+    title: "LAN Party",
+    tags: &["graph", "cliques"],
+    ..Metadata::EMPTY
+};
+
+#[aoc(day = 23, part = 1)]
+fn solve_part_1(pairs: &[[SmolStr; 2]]) -> usize {
+    let sets = find_sets_with_t(pairs);
+    sets.len()
+}
+
+#[aoc(day = 23, part = 2)]
+fn solve_part_2(pairs: &[[SmolStr; 2]]) -> String {
+    find_largest_cluster(pairs).iter().sorted().join(",")
+}
+
+fn find_sets_with_t(connections: &[[SmolStr; 2]]) -> Vec<HashSet<SmolStr>> {
+    let mapping = get_mapping(connections);
+
+    mapping
+        .keys()
+        .cloned()
+        .combinations(3)
+        .filter(|t| {
+            t.iter().any(|item| item.starts_with("t"))
+                && t.iter().all(|item| {
+                    t.iter()
+                        .all(|other_item| other_item == item || mapping[item].contains(other_item))
+                })
+        })
+        .map(HashSet::from_iter)
+        .collect()
+}
+
+/// Finds the largest set of computers all connected to each other, with Bron–Kerbosch.
+fn find_largest_cluster(connections: &[[SmolStr; 2]]) -> HashSet<SmolStr> {
+    let mapping = get_mapping(connections);
+    let mut largest = HashSet::new();
+    extend_cluster(
+        &mapping,
+        HashSet::new(),
+        mapping.keys().cloned().collect(),
+        HashSet::new(),
+        &mut largest,
+    );
+    largest
+}
+
+/// Grows `cluster` with the `candidates` connected to all of it, skipping the `excluded` ones
+/// whose clusters were already explored.
+fn extend_cluster(
+    mapping: &HashMap<SmolStr, HashSet<SmolStr>>,
+    cluster: HashSet<SmolStr>,
+    mut candidates: HashSet<SmolStr>,
+    mut excluded: HashSet<SmolStr>,
+    largest: &mut HashSet<SmolStr>,
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        if cluster.len() > largest.len() {
+            *largest = cluster;
+        }
+        return;
+    }
+    // the computers connected to the pivot are explored through the ones that aren't
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|item| mapping[*item].len())
+        .unwrap()
+        .clone();
+    let to_explore = candidates
+        .difference(&mapping[&pivot])
+        .cloned()
+        .collect_vec();
+    for item in to_explore {
+        let connected = &mapping[&item];
+        let mut extended = cluster.clone();
+        extended.insert(item.clone());
+        extend_cluster(
+            mapping,
+            extended,
+            candidates.intersection(connected).cloned().collect(),
+            excluded.intersection(connected).cloned().collect(),
+            largest,
+        );
+        candidates.remove(&item);
+        excluded.insert(item);
+    }
+}
+
+fn get_mapping(connections: &[[SmolStr; 2]]) -> HashMap<SmolStr, HashSet<SmolStr>> {
+    let mapping = connections
+        .iter()
+        .cloned()
+        .flat_map(|[a, b]| [(a.clone(), b.clone()), (b, a)])
+        .into_grouping_map()
+        .fold(HashSet::new(), |mut acc, _key, v| {
+            acc.insert(v);
+            acc
+        });
+    mapping
+}
+
+#[aoc(day = 23, parser)]
+fn parse(input: &impl Lines) -> Result<Vec<[SmolStr; 2]>, ParseError> {
+    input
+        .get_lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            l.split("-")
+                .map(SmolStr::from)
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| input.parse_error(l, "expected a connection like kh-tc"))
+        })
+        .collect()
+}